@@ -32,6 +32,16 @@ pub fn ghost(_: TS1) -> TS1 {
     quote::quote! { creusot_contracts::ghost::Ghost::new() }.into()
 }
 
+#[proc_macro]
+pub fn label(_: TS1) -> TS1 {
+    TS1::new()
+}
+
+#[proc_macro]
+pub fn reveal(_: TS1) -> TS1 {
+    TS1::new()
+}
+
 #[proc_macro]
 pub fn pearlite(_: TS1) -> TS1 {
     TS1::new()
@@ -52,11 +62,56 @@ pub fn law(_: TS1, _: TS1) -> TS1 {
     TS1::new()
 }
 
+#[proc_macro_attribute]
+pub fn lemma(_: TS1, _: TS1) -> TS1 {
+    TS1::new()
+}
+
+#[proc_macro_attribute]
+pub fn open(_: TS1, tokens: TS1) -> TS1 {
+    tokens
+}
+
+#[proc_macro_attribute]
+pub fn opaque(_: TS1, tokens: TS1) -> TS1 {
+    tokens
+}
+
 #[proc_macro_attribute]
 pub fn trusted(_: TS1, tokens: TS1) -> TS1 {
     tokens
 }
 
+#[proc_macro_attribute]
+pub fn ensures_ok(_: TS1, tokens: TS1) -> TS1 {
+    tokens
+}
+
+#[proc_macro_attribute]
+pub fn ensures_err(_: TS1, tokens: TS1) -> TS1 {
+    tokens
+}
+
+#[proc_macro_attribute]
+pub fn ensures_some(_: TS1, tokens: TS1) -> TS1 {
+    tokens
+}
+
+#[proc_macro_attribute]
+pub fn ensures_none(_: TS1, tokens: TS1) -> TS1 {
+    tokens
+}
+
+#[proc_macro_attribute]
+pub fn may_panic(_: TS1, tokens: TS1) -> TS1 {
+    tokens
+}
+
+#[proc_macro_attribute]
+pub fn terminates(_: TS1, tokens: TS1) -> TS1 {
+    tokens
+}
+
 #[proc_macro]
 pub fn extern_spec(_: TS1) -> TS1 {
     TS1::new()
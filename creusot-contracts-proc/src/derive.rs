@@ -1,7 +1,33 @@
 mod clone;
+mod debug;
+mod deep_model;
+mod default;
+mod hash;
 mod invariant;
+mod ord;
+mod ord_logic;
 mod partial_eq;
+mod resolve;
+mod shallow_model;
 
 pub use clone::*;
+pub use debug::*;
+pub use deep_model::*;
+pub use default::*;
+pub use hash::*;
 pub use invariant::*;
+pub use ord::*;
+pub use ord_logic::*;
 pub use partial_eq::*;
+pub use resolve::*;
+pub use shallow_model::*;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+fn conjunction<I: Iterator<Item = TokenStream>>(mut conjuncts: I) -> TokenStream {
+    match conjuncts.next() {
+        Some(first) => quote! { #first #(&& #conjuncts)* },
+        None => quote!(true),
+    }
+}
@@ -0,0 +1,99 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Data, DeriveInput, Fields, GenericParam,
+    Generics, Index,
+};
+
+pub fn derive_debug(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = input.ident;
+
+    let generics = add_trait_bounds(input.generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let fmt = debug(&name, &input.data);
+
+    // Formatting has no specification, so the body is trusted.
+    let expanded = quote! {
+        impl #impl_generics ::std::fmt::Debug for #name #ty_generics #where_clause {
+            #[::creusot_contracts::trusted]
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                #fmt
+            }
+        }
+    };
+
+    proc_macro::TokenStream::from(expanded)
+}
+
+fn add_trait_bounds(mut generics: Generics) -> Generics {
+    for param in &mut generics.params {
+        if let GenericParam::Type(ref mut type_param) = *param {
+            type_param.bounds.push(parse_quote!(::std::fmt::Debug));
+        }
+    }
+    generics
+}
+
+fn debug(base_ident: &Ident, data: &Data) -> TokenStream {
+    let name = base_ident.to_string();
+    match *data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => {
+                let recurse = fields.named.iter().map(|f| {
+                    let ident = f.ident.as_ref().unwrap();
+                    let field_name = ident.to_string();
+                    quote_spanned! {f.span()=>
+                        .field(#field_name, &self.#ident)
+                    }
+                });
+                quote! { f.debug_struct(#name) #(#recurse)* .finish() }
+            }
+            Fields::Unnamed(ref fields) => {
+                let recurse = fields.unnamed.iter().enumerate().map(|(i, f)| {
+                    let index = Index::from(i);
+                    quote_spanned! {f.span()=>
+                        .field(&self.#index)
+                    }
+                });
+                quote! { f.debug_tuple(#name) #(#recurse)* .finish() }
+            }
+            Fields::Unit => quote! { f.write_str(#name) },
+        },
+        Data::Enum(ref data) => {
+            let arms = data.variants.iter().map(|v| {
+                let ident = &v.ident;
+                let variant_name = ident.to_string();
+                match &v.fields {
+                    Fields::Named(fields) => {
+                        let names: Vec<_> =
+                            fields.named.iter().map(|f| f.ident.clone().unwrap()).collect();
+                        let field_names = names.iter().map(|n| n.to_string());
+                        quote! {
+                            #base_ident::#ident{#(#names),*} =>
+                                f.debug_struct(#variant_name) #(.field(#field_names, #names))* .finish()
+                        }
+                    }
+                    Fields::Unnamed(fields) => {
+                        let names: Vec<_> =
+                            (0..fields.unnamed.len()).map(|i| format_ident!("v{}", i)).collect();
+                        quote! {
+                            #base_ident::#ident(#(#names),*) =>
+                                f.debug_tuple(#variant_name) #(.field(#names))* .finish()
+                        }
+                    }
+                    Fields::Unit => quote! { #base_ident::#ident => f.write_str(#variant_name) },
+                }
+            });
+
+            quote! {
+                match self {
+                    #(#arms),*
+                }
+            }
+        }
+        Data::Union(_) => todo!(),
+    }
+}
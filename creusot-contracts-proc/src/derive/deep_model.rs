@@ -0,0 +1,251 @@
+use super::ord_logic::ord_logic_impl;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Attribute, Data, DeriveInput, Fields,
+    GenericParam, Generics, Index, Lit, Meta, Path, PathArguments, Type, Visibility,
+};
+
+pub fn derive_deep_model(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = input.ident;
+    let vis = input.vis;
+
+    let generics = add_trait_bounds(input.generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let (model_ty, model_ctor, model_decl) = match deep_model_ty_attr(&input.attrs) {
+        Ok(Some(Type::Path(ty))) => {
+            let ctor = strip_generics(ty.path.clone());
+            (quote!(#ty), ctor, quote!())
+        }
+        Ok(Some(ty)) => {
+            return syn::Error::new_spanned(ty, "expected a path to a struct or enum")
+                .to_compile_error()
+                .into()
+        }
+        Ok(None) => {
+            let model_name = format_ident!("{}DeepModel", name);
+            let (decl, field_tys) = deep_model_ty(&vis, &model_name, &input.data);
+            let model_ty = quote! {
+                #model_name <#(<#field_tys as ::creusot_contracts::model::DeepModel>::DeepModelTy),*>
+            };
+
+            // Generated models are ordered lexicographically, like the derived `PartialOrd`
+            let params = model_params(field_tys.len());
+            let model_generics = parse_quote!(<#(#params: ::creusot_contracts::logic::OrdLogic),*>);
+            let ord = ord_logic_impl(&model_name, &model_generics, &input.data);
+
+            (model_ty, model_name.into(), quote!(#decl #ord))
+        }
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let body = deep_model(&name, &model_ctor, &input.data);
+
+    let expanded = quote! {
+        #model_decl
+
+        impl #impl_generics ::creusot_contracts::model::DeepModel for #name #ty_generics #where_clause {
+            type DeepModelTy = #model_ty;
+
+            #[::creusot_contracts::logic]
+            fn deep_model(self) -> Self::DeepModelTy {
+                #body
+            }
+        }
+    };
+
+    proc_macro::TokenStream::from(expanded)
+}
+
+fn add_trait_bounds(mut generics: Generics) -> Generics {
+    for param in &mut generics.params {
+        if let GenericParam::Type(ref mut type_param) = *param {
+            type_param.bounds.push(parse_quote!(::creusot_contracts::model::DeepModel));
+        }
+    }
+    generics
+}
+
+// Looks for a `#[DeepModelTy = "Path<..>"]` attribute, which reuses an existing type with the
+// same shape as the deep model instead of generating a fresh one.
+fn deep_model_ty_attr(attrs: &[Attribute]) -> syn::Result<Option<Type>> {
+    let Some(attr) = attrs.iter().find(|a| a.path.is_ident("DeepModelTy")) else {
+        return Ok(None);
+    };
+
+    match attr.parse_meta()? {
+        Meta::NameValue(nv) => match nv.lit {
+            Lit::Str(s) => Ok(Some(s.parse()?)),
+            lit => Err(syn::Error::new_spanned(lit, "expected a string literal")),
+        },
+        meta => Err(syn::Error::new_spanned(meta, "expected `#[DeepModelTy = \"...\"]`")),
+    }
+}
+
+fn strip_generics(mut path: Path) -> Path {
+    if let Some(seg) = path.segments.last_mut() {
+        seg.arguments = PathArguments::None;
+    }
+    path
+}
+
+// The deep model type gets one type parameter per field, instantiated with the deep model of
+// that field's type, since associated types cannot appear in type declarations.
+fn deep_model_ty(vis: &Visibility, ident: &Ident, data: &Data) -> (TokenStream, Vec<Type>) {
+    let mut field_tys = Vec::new();
+    let decl = match *data {
+        Data::Struct(ref data) => {
+            let fields = deep_model_ty_fields(&data.fields, &mut field_tys);
+            let params = model_params(field_tys.len());
+            match data.fields {
+                Fields::Named(_) => quote! {
+                    #vis struct #ident <#(#params),*> #fields
+                },
+                _ => quote! {
+                    #vis struct #ident <#(#params),*> #fields;
+                },
+            }
+        }
+        Data::Enum(ref data) => {
+            let variants: Vec<_> = data
+                .variants
+                .iter()
+                .map(|v| {
+                    let ident = &v.ident;
+                    let fields = deep_model_ty_fields(&v.fields, &mut field_tys);
+                    quote! { #ident #fields }
+                })
+                .collect();
+            let params = model_params(field_tys.len());
+            quote! {
+                #vis enum #ident <#(#params),*> {
+                    #(#variants),*
+                }
+            }
+        }
+        Data::Union(_) => todo!(),
+    };
+    (decl, field_tys)
+}
+
+fn model_params(n: usize) -> Vec<Ident> {
+    (0..n).map(|i| format_ident!("M{}", i)).collect()
+}
+
+fn deep_model_ty_fields(fields: &Fields, field_tys: &mut Vec<Type>) -> TokenStream {
+    let mut param = |f: &syn::Field| {
+        let param = format_ident!("M{}", field_tys.len());
+        field_tys.push(f.ty.clone());
+        param
+    };
+
+    match fields {
+        Fields::Named(ref fields) => {
+            let recurse = fields.named.iter().map(|f| {
+                let name = &f.ident;
+                let param = param(f);
+                quote_spanned! {f.span()=> #name: #param }
+            });
+            quote! { { #(#recurse),* } }
+        }
+        Fields::Unnamed(ref fields) => {
+            let recurse = fields.unnamed.iter().map(|f| {
+                let param = param(f);
+                quote_spanned! {f.span()=> #param }
+            });
+            quote! { ( #(#recurse),* ) }
+        }
+        Fields::Unit => quote!(),
+    }
+}
+
+fn deep_model(base_ident: &Ident, model_ident: &Path, data: &Data) -> TokenStream {
+    match *data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => {
+                let recurse = fields.named.iter().map(|f| {
+                    let name = &f.ident;
+                    quote_spanned! {f.span()=>
+                        #name: ::creusot_contracts::model::DeepModel::deep_model(self.#name)
+                    }
+                });
+                quote! {
+                    #model_ident { #(#recurse),* }
+                }
+            }
+            Fields::Unnamed(ref fields) => {
+                let recurse = fields.unnamed.iter().enumerate().map(|(i, f)| {
+                    let index = Index::from(i);
+                    quote_spanned! {f.span()=>
+                        ::creusot_contracts::model::DeepModel::deep_model(self.#index)
+                    }
+                });
+                quote! {
+                    #model_ident (#(#recurse),*)
+                }
+            }
+            Fields::Unit => quote! {
+                #model_ident
+            },
+        },
+        Data::Enum(ref data) => {
+            let arms = data.variants.iter().map(|v| {
+                let ident = &v.ident;
+                match &v.fields {
+                    Fields::Named(fields) => {
+                        let arm = gen_match_arm(fields.named.iter());
+                        let fields1 = arm.fields;
+                        let body = arm.body;
+                        quote! { #base_ident::#ident{#(#fields1),* } => #model_ident::#ident{#(#body),* }}
+                    }
+                    Fields::Unnamed(fields) => {
+                        let arm = gen_match_arm(fields.unnamed.iter());
+                        let fields1 = arm.fields;
+                        let body = arm.body;
+                        quote! { #base_ident::#ident(#(#fields1),*) => #model_ident::#ident(#(#body),* ) }
+                    }
+                    Fields::Unit => quote! {#base_ident::#ident => #model_ident::#ident},
+                }
+            });
+
+            quote! {
+                match self {
+                    #(#arms),*
+                }
+            }
+        }
+        Data::Union(_) => todo!(),
+    }
+}
+
+struct ArmAcc {
+    fields: Vec<TokenStream>,
+    body: Vec<TokenStream>,
+}
+
+fn gen_match_arm<'a, I: Iterator<Item = &'a syn::Field>>(fields: I) -> ArmAcc {
+    let mut acc = ArmAcc { fields: Vec::new(), body: Vec::new() };
+
+    for (i, field) in fields.enumerate() {
+        let named = field.ident.is_some();
+        let name_base = match &field.ident {
+            Some(ident) => format_ident!("{}", ident),
+            None => format_ident!("v{}", i),
+        };
+        let name_1 = format_ident!("{}_1", name_base);
+
+        let call = quote!(::creusot_contracts::model::DeepModel::deep_model(#name_1));
+        if named {
+            acc.fields.push(quote!(#name_base: #name_1));
+            acc.body.push(quote!(#name_base: #call));
+        } else {
+            acc.fields.push(quote!(#name_1));
+            acc.body.push(quote!(#call));
+        }
+    }
+
+    acc
+}
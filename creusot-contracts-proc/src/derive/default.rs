@@ -0,0 +1,130 @@
+use super::conjunction;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, quote_spanned};
+
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Data, DeriveInput, Fields, GenericParam,
+    Generics, Index,
+};
+
+pub fn derive_default(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = input.ident;
+
+    let generics = add_trait_bounds(input.generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let (default, is_default) = match default(&name, &input.data) {
+        Ok(res) => res,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let expanded = quote! {
+        impl #impl_generics ::std::default::Default for #name #ty_generics #where_clause {
+            #[::creusot_contracts::ensures(::creusot_contracts::std::default::Default::is_default(result))]
+            fn default() -> Self {
+                #default
+            }
+        }
+
+        impl #impl_generics ::creusot_contracts::std::default::Default for #name #ty_generics #where_clause {
+            #[::creusot_contracts::predicate]
+            fn is_default(self) -> bool {
+                #is_default
+            }
+        }
+    };
+
+    proc_macro::TokenStream::from(expanded)
+}
+
+fn add_trait_bounds(mut generics: Generics) -> Generics {
+    for param in &mut generics.params {
+        if let GenericParam::Type(ref mut type_param) = *param {
+            type_param.bounds.push(parse_quote!(::creusot_contracts::std::default::Default));
+        }
+    }
+    generics
+}
+
+// Returns the body of `default` and of `is_default`
+fn default(base_ident: &Ident, data: &Data) -> syn::Result<(TokenStream, TokenStream)> {
+    match *data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => {
+                let defaults = fields.named.iter().map(|f| {
+                    let name = &f.ident;
+                    quote_spanned! {f.span()=>
+                        #name: ::std::default::Default::default()
+                    }
+                });
+                let is_defaults = fields.named.iter().map(|f| {
+                    let name = &f.ident;
+                    quote_spanned! {f.span()=>
+                        ::creusot_contracts::std::default::Default::is_default(self.#name)
+                    }
+                });
+                Ok((quote!(#base_ident { #(#defaults),* }), conjunction(is_defaults)))
+            }
+            Fields::Unnamed(ref fields) => {
+                let defaults = fields.unnamed.iter().map(|f| {
+                    quote_spanned! {f.span()=>
+                        ::std::default::Default::default()
+                    }
+                });
+                let is_defaults = fields.unnamed.iter().enumerate().map(|(i, f)| {
+                    let index = Index::from(i);
+                    quote_spanned! {f.span()=>
+                        ::creusot_contracts::std::default::Default::is_default(self.#index)
+                    }
+                });
+                Ok((quote!(#base_ident ( #(#defaults),* )), conjunction(is_defaults)))
+            }
+            Fields::Unit => Ok((quote!(#base_ident), quote!(true))),
+        },
+        Data::Enum(ref data) => {
+            let mut defaults = data
+                .variants
+                .iter()
+                .filter(|v| v.attrs.iter().any(|attr| attr.path.is_ident("default")));
+
+            let variant = match (defaults.next(), defaults.next()) {
+                (Some(variant), None) => variant,
+                (None, _) => {
+                    return Err(syn::Error::new(
+                        data.enum_token.span(),
+                        "no default declared, mark a unit variant as `#[default]`",
+                    ))
+                }
+                (Some(_), Some(other)) => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        "multiple declared defaults, only one variant can be `#[default]`",
+                    ))
+                }
+            };
+
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "the `#[default]` attribute may only be used on unit enum variants",
+                ));
+            }
+
+            let ident = &variant.ident;
+            let is_default = if data.variants.len() > 1 {
+                quote! {
+                    match self {
+                        #base_ident::#ident => true,
+                        _ => false
+                    }
+                }
+            } else {
+                quote!(true)
+            };
+            Ok((quote!(#base_ident::#ident), is_default))
+        }
+        Data::Union(_) => todo!(),
+    }
+}
@@ -0,0 +1,106 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Data, DeriveInput, Fields, GenericParam,
+    Generics, Index,
+};
+
+pub fn derive_hash(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = input.ident;
+
+    let generics = add_trait_bounds(input.generics, &name);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let hash = hash(&name, &input.data);
+
+    // Hashing field by field does not literally hash the deep model, so the implementation
+    // is trusted to only depend on it.
+    let expanded = quote! {
+        impl #impl_generics ::std::hash::Hash for #name #ty_generics #where_clause {
+            #[::creusot_contracts::trusted]
+            #[::creusot_contracts::ensures(^state == ::creusot_contracts::std::hash::hash_log(
+                *state, ::creusot_contracts::model::DeepModel::deep_model(*self)))]
+            fn hash<__H: ::std::hash::Hasher>(&self, state: &mut __H) {
+                #hash
+            }
+        }
+    };
+
+    proc_macro::TokenStream::from(expanded)
+}
+
+fn add_trait_bounds(mut generics: Generics, name: &Ident) -> Generics {
+    for param in &mut generics.params {
+        if let GenericParam::Type(ref mut type_param) = *param {
+            type_param.bounds.push(parse_quote!(::std::hash::Hash));
+            type_param.bounds.push(parse_quote!(::creusot_contracts::model::DeepModel));
+        }
+    }
+
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let self_ty: syn::Type = parse_quote!(#name #ty_generics);
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#self_ty: ::creusot_contracts::model::DeepModel));
+    generics
+}
+
+fn hash(base_ident: &Ident, data: &Data) -> TokenStream {
+    match *data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => {
+                let recurse = fields.named.iter().map(|f| {
+                    let name = &f.ident;
+                    quote_spanned! {f.span()=>
+                        ::std::hash::Hash::hash(&self.#name, state);
+                    }
+                });
+                quote! { #(#recurse)* }
+            }
+            Fields::Unnamed(ref fields) => {
+                let recurse = fields.unnamed.iter().enumerate().map(|(i, f)| {
+                    let index = Index::from(i);
+                    quote_spanned! {f.span()=>
+                        ::std::hash::Hash::hash(&self.#index, state);
+                    }
+                });
+                quote! { #(#recurse)* }
+            }
+            Fields::Unit => quote!(),
+        },
+        Data::Enum(ref data) => {
+            let arms = data.variants.iter().enumerate().map(|(ix, v)| {
+                let ident = &v.ident;
+                let (pattern, names) = match &v.fields {
+                    Fields::Named(fields) => {
+                        let names: Vec<_> =
+                            fields.named.iter().map(|f| f.ident.clone().unwrap()).collect();
+                        (quote!(#base_ident::#ident{#(#names),*}), names)
+                    }
+                    Fields::Unnamed(fields) => {
+                        let names: Vec<_> =
+                            (0..fields.unnamed.len()).map(|i| format_ident!("v{}", i)).collect();
+                        (quote!(#base_ident::#ident(#(#names),*)), names)
+                    }
+                    Fields::Unit => (quote!(#base_ident::#ident), Vec::new()),
+                };
+                quote! {
+                    #pattern => {
+                        ::std::hash::Hash::hash(&#ix, state);
+                        #(::std::hash::Hash::hash(#names, state);)*
+                    }
+                }
+            });
+
+            quote! {
+                match self {
+                    #(#arms),*
+                }
+            }
+        }
+        Data::Union(_) => todo!(),
+    }
+}
@@ -0,0 +1,238 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Data, DeriveInput, Fields, GenericParam,
+    Generics, Index, Path,
+};
+
+pub fn derive_partial_ord(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = input.ident;
+
+    let generics = add_trait_bounds(input.generics, &name, parse_quote!(::std::cmp::PartialOrd));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let cmp = Comparison {
+        trait_: parse_quote!(::std::cmp::PartialOrd),
+        method: format_ident!("partial_cmp"),
+        partial: true,
+    };
+    let body = cmp.body(&name, &input.data);
+
+    let expanded = quote! {
+        impl #impl_generics ::std::cmp::PartialOrd for #name #ty_generics #where_clause {
+            #[::creusot_contracts::ensures(result == Some(
+                ::creusot_contracts::logic::OrdLogic::cmp_log(
+                    ::creusot_contracts::model::DeepModel::deep_model(*self),
+                    ::creusot_contracts::model::DeepModel::deep_model(*rhs))))]
+            fn partial_cmp(&self, rhs: &Self) -> ::std::option::Option<::std::cmp::Ordering> {
+                #body
+            }
+        }
+    };
+
+    proc_macro::TokenStream::from(expanded)
+}
+
+pub fn derive_ord(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = input.ident;
+
+    let generics = add_trait_bounds(input.generics, &name, parse_quote!(::std::cmp::Ord));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let cmp = Comparison {
+        trait_: parse_quote!(::std::cmp::Ord),
+        method: format_ident!("cmp"),
+        partial: false,
+    };
+    let body = cmp.body(&name, &input.data);
+
+    let expanded = quote! {
+        impl #impl_generics ::std::cmp::Ord for #name #ty_generics #where_clause {
+            #[::creusot_contracts::ensures(result ==
+                ::creusot_contracts::logic::OrdLogic::cmp_log(
+                    ::creusot_contracts::model::DeepModel::deep_model(*self),
+                    ::creusot_contracts::model::DeepModel::deep_model(*rhs)))]
+            fn cmp(&self, rhs: &Self) -> ::std::cmp::Ordering {
+                #body
+            }
+        }
+    };
+
+    proc_macro::TokenStream::from(expanded)
+}
+
+// The specifications of `PartialOrd` and `Ord` require the deep model of the type, and of every
+// type parameter, to be ordered.
+fn add_trait_bounds(mut generics: Generics, name: &Ident, trait_: Path) -> Generics {
+    let mut params = Vec::new();
+    for param in &mut generics.params {
+        if let GenericParam::Type(ref mut type_param) = *param {
+            type_param.bounds.push(parse_quote!(#trait_));
+            type_param.bounds.push(parse_quote!(::creusot_contracts::model::DeepModel));
+            params.push(type_param.ident.clone());
+        }
+    }
+
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let self_ty: syn::Type = parse_quote!(#name #ty_generics);
+
+    let where_clause = generics.make_where_clause();
+    for param in params {
+        where_clause.predicates.push(parse_quote! {
+            <#param as ::creusot_contracts::model::DeepModel>::DeepModelTy: ::creusot_contracts::logic::OrdLogic
+        });
+    }
+    where_clause.predicates.push(parse_quote!(#self_ty: ::creusot_contracts::model::DeepModel));
+    where_clause.predicates.push(parse_quote! {
+        <#self_ty as ::creusot_contracts::model::DeepModel>::DeepModelTy: ::creusot_contracts::logic::OrdLogic
+    });
+    generics
+}
+
+struct Comparison {
+    trait_: Path,
+    method: Ident,
+    // Whether the comparison returns an `Option<Ordering>`
+    partial: bool,
+}
+
+impl Comparison {
+    fn wrap(&self, ord: TokenStream) -> TokenStream {
+        if self.partial {
+            quote!(::std::option::Option::Some(#ord))
+        } else {
+            ord
+        }
+    }
+
+    fn body(&self, base_ident: &Ident, data: &Data) -> TokenStream {
+        let (trait_, method) = (&self.trait_, &self.method);
+        match *data {
+            Data::Struct(ref data) => match data.fields {
+                Fields::Named(ref fields) => {
+                    let cmps = fields.named.iter().map(|f| {
+                        let name = &f.ident;
+                        quote_spanned! {f.span()=>
+                            #trait_::#method(&self.#name, &rhs.#name)
+                        }
+                    });
+                    self.lexicographic(cmps.collect())
+                }
+                Fields::Unnamed(ref fields) => {
+                    let cmps = fields.unnamed.iter().enumerate().map(|(i, f)| {
+                        let index = Index::from(i);
+                        quote_spanned! {f.span()=>
+                            #trait_::#method(&self.#index, &rhs.#index)
+                        }
+                    });
+                    self.lexicographic(cmps.collect())
+                }
+                Fields::Unit => self.wrap(quote!(::std::cmp::Ordering::Equal)),
+            },
+            Data::Enum(ref data) => {
+                let last = data.variants.len().saturating_sub(1);
+                let less = self.wrap(quote!(::std::cmp::Ordering::Less));
+                let greater = self.wrap(quote!(::std::cmp::Ordering::Greater));
+                let arms = data.variants.iter().enumerate().map(|(ix, v)| {
+                    let ident = &v.ident;
+                    let (pattern_left, pattern_right, cmps) = match &v.fields {
+                        Fields::Named(fields) => {
+                            let arm = self.gen_match_arm(fields.named.iter());
+                            let (fields1, fields2) = (arm.pattern_left, arm.pattern_right);
+                            (
+                                quote!(#base_ident::#ident{#(#fields1),*}),
+                                quote!(#base_ident::#ident{#(#fields2),*}),
+                                arm.cmps,
+                            )
+                        }
+                        Fields::Unnamed(fields) => {
+                            let arm = self.gen_match_arm(fields.unnamed.iter());
+                            let (fields1, fields2) = (arm.pattern_left, arm.pattern_right);
+                            (
+                                quote!(#base_ident::#ident(#(#fields1),*)),
+                                quote!(#base_ident::#ident(#(#fields2),*)),
+                                arm.cmps,
+                            )
+                        }
+                        Fields::Unit => {
+                            (quote!(#base_ident::#ident), quote!(#base_ident::#ident), Vec::new())
+                        }
+                    };
+                    let body = self.lexicographic(cmps);
+
+                    // The last variant is necessarily compared with itself at this point
+                    if ix == last {
+                        quote! { (#pattern_left, #pattern_right) => #body }
+                    } else {
+                        quote! {
+                            (#pattern_left, #pattern_right) => #body,
+                            (#base_ident::#ident{..}, _) => #less,
+                            (_, #base_ident::#ident{..}) => #greater
+                        }
+                    }
+                });
+
+                quote! {
+                    match (self, rhs) {
+                        #(#arms),*
+                    }
+                }
+            }
+            Data::Union(_) => todo!(),
+        }
+    }
+
+    // Chain a list of comparisons, returning the first that isn't `Equal`.
+    fn lexicographic(&self, cmps: Vec<TokenStream>) -> TokenStream {
+        let equal = self.wrap(quote!(::std::cmp::Ordering::Equal));
+        cmps.into_iter()
+            .rev()
+            .fold(None, |acc, cmp| match acc {
+                None => Some(cmp),
+                Some(rest) => Some(quote! {
+                    match #cmp {
+                        #equal => #rest,
+                        c => c,
+                    }
+                }),
+            })
+            .unwrap_or(equal)
+    }
+
+    fn gen_match_arm<'a, I: Iterator<Item = &'a syn::Field>>(&self, fields: I) -> ArmAcc {
+        let (trait_, method) = (&self.trait_, &self.method);
+        let mut acc =
+            ArmAcc { pattern_left: Vec::new(), pattern_right: Vec::new(), cmps: Vec::new() };
+
+        for (i, field) in fields.enumerate() {
+            let named = field.ident.is_some();
+            let name_base = match &field.ident {
+                Some(ident) => format_ident!("{}", ident),
+                None => format_ident!("v{}", i),
+            };
+            let name_1 = format_ident!("{}_1", name_base);
+            let name_2 = format_ident!("{}_2", name_base);
+
+            acc.cmps.push(quote!(#trait_::#method(#name_1, #name_2)));
+            if named {
+                acc.pattern_left.push(quote!(#name_base: #name_1));
+                acc.pattern_right.push(quote!(#name_base: #name_2));
+            } else {
+                acc.pattern_left.push(quote!(#name_1));
+                acc.pattern_right.push(quote!(#name_2));
+            }
+        }
+
+        acc
+    }
+}
+
+struct ArmAcc {
+    pattern_left: Vec<TokenStream>,
+    pattern_right: Vec<TokenStream>,
+    cmps: Vec<TokenStream>,
+}
@@ -0,0 +1,203 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Data, DeriveInput, Fields, GenericParam,
+    Generics, Index,
+};
+
+pub fn derive_ord_logic(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let generics = add_trait_bounds(input.generics);
+
+    proc_macro::TokenStream::from(ord_logic_impl(&input.ident, &generics, &input.data))
+}
+
+fn add_trait_bounds(mut generics: Generics) -> Generics {
+    for param in &mut generics.params {
+        if let GenericParam::Type(ref mut type_param) = *param {
+            type_param.bounds.push(parse_quote!(::creusot_contracts::logic::OrdLogic));
+        }
+    }
+    generics
+}
+
+// The lexicographic order on `data`: variants are ordered by declaration order and fields are
+// compared from first to last, like the order derived by `PartialOrd` and `Ord`.
+// The laws follow from those of the fields, by the case analysis of `cmp_log`.
+pub(crate) fn ord_logic_impl(name: &Ident, generics: &Generics, data: &Data) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let cmp = cmp_log(name, data);
+    let ord = quote!(::creusot_contracts::logic::OrdLogic);
+    let ordering = quote!(::std::cmp::Ordering);
+
+    quote! {
+        impl #impl_generics #ord for #name #ty_generics #where_clause {
+            #[::creusot_contracts::logic]
+            fn cmp_log(self, o: Self) -> #ordering {
+                #cmp
+            }
+
+            #[::creusot_contracts::law]
+            #[::creusot_contracts::ensures(#ord::le_log(x, y) == (#ord::cmp_log(x, y) != #ordering::Greater))]
+            fn cmp_le_log(x: Self, y: Self) {}
+
+            #[::creusot_contracts::law]
+            #[::creusot_contracts::ensures(#ord::lt_log(x, y) == (#ord::cmp_log(x, y) == #ordering::Less))]
+            fn cmp_lt_log(x: Self, y: Self) {}
+
+            #[::creusot_contracts::law]
+            #[::creusot_contracts::ensures(#ord::ge_log(x, y) == (#ord::cmp_log(x, y) != #ordering::Less))]
+            fn cmp_ge_log(x: Self, y: Self) {}
+
+            #[::creusot_contracts::law]
+            #[::creusot_contracts::ensures(#ord::gt_log(x, y) == (#ord::cmp_log(x, y) == #ordering::Greater))]
+            fn cmp_gt_log(x: Self, y: Self) {}
+
+            #[::creusot_contracts::law]
+            #[::creusot_contracts::ensures(#ord::cmp_log(x, x) == #ordering::Equal)]
+            fn refl(x: Self) {}
+
+            #[::creusot_contracts::law]
+            #[::creusot_contracts::requires(#ord::cmp_log(x, y) == o)]
+            #[::creusot_contracts::requires(#ord::cmp_log(y, z) == o)]
+            #[::creusot_contracts::ensures(#ord::cmp_log(x, z) == o)]
+            fn trans(x: Self, y: Self, z: Self, o: #ordering) {}
+
+            #[::creusot_contracts::law]
+            #[::creusot_contracts::requires(#ord::cmp_log(x, y) == #ordering::Less)]
+            #[::creusot_contracts::ensures(#ord::cmp_log(y, x) == #ordering::Greater)]
+            fn antisym1(x: Self, y: Self) {}
+
+            #[::creusot_contracts::law]
+            #[::creusot_contracts::requires(#ord::cmp_log(x, y) == #ordering::Greater)]
+            #[::creusot_contracts::ensures(#ord::cmp_log(y, x) == #ordering::Less)]
+            fn antisym2(x: Self, y: Self) {}
+
+            #[::creusot_contracts::law]
+            #[::creusot_contracts::ensures((x == y) == (#ord::cmp_log(x, y) == #ordering::Equal))]
+            fn eq_cmp(x: Self, y: Self) {}
+        }
+    }
+}
+
+fn cmp_log(base_ident: &Ident, data: &Data) -> TokenStream {
+    match *data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => {
+                let cmps = fields.named.iter().map(|f| {
+                    let name = &f.ident;
+                    quote_spanned! {f.span()=>
+                        ::creusot_contracts::logic::OrdLogic::cmp_log(self.#name, o.#name)
+                    }
+                });
+                lexicographic(cmps.collect())
+            }
+            Fields::Unnamed(ref fields) => {
+                let cmps = fields.unnamed.iter().enumerate().map(|(i, f)| {
+                    let index = Index::from(i);
+                    quote_spanned! {f.span()=>
+                        ::creusot_contracts::logic::OrdLogic::cmp_log(self.#index, o.#index)
+                    }
+                });
+                lexicographic(cmps.collect())
+            }
+            Fields::Unit => quote!(::std::cmp::Ordering::Equal),
+        },
+        Data::Enum(ref data) => {
+            let last = data.variants.len().saturating_sub(1);
+            let arms = data.variants.iter().enumerate().map(|(ix, v)| {
+                let ident = &v.ident;
+                let (pattern_left, pattern_right, cmps) = match &v.fields {
+                    Fields::Named(fields) => {
+                        let arm = gen_match_arm(fields.named.iter());
+                        let (fields1, fields2) = (arm.pattern_left, arm.pattern_right);
+                        (
+                            quote!(#base_ident::#ident{#(#fields1),*}),
+                            quote!(#base_ident::#ident{#(#fields2),*}),
+                            arm.cmps,
+                        )
+                    }
+                    Fields::Unnamed(fields) => {
+                        let arm = gen_match_arm(fields.unnamed.iter());
+                        let (fields1, fields2) = (arm.pattern_left, arm.pattern_right);
+                        (
+                            quote!(#base_ident::#ident(#(#fields1),*)),
+                            quote!(#base_ident::#ident(#(#fields2),*)),
+                            arm.cmps,
+                        )
+                    }
+                    Fields::Unit => {
+                        (quote!(#base_ident::#ident), quote!(#base_ident::#ident), Vec::new())
+                    }
+                };
+                let body = lexicographic(cmps);
+
+                // The last variant is necessarily compared with itself at this point
+                if ix == last {
+                    quote! { (#pattern_left, #pattern_right) => #body }
+                } else {
+                    quote! {
+                        (#pattern_left, #pattern_right) => #body,
+                        (#base_ident::#ident{..}, _) => ::std::cmp::Ordering::Less,
+                        (_, #base_ident::#ident{..}) => ::std::cmp::Ordering::Greater
+                    }
+                }
+            });
+
+            quote! {
+                match (self, o) {
+                    #(#arms),*
+                }
+            }
+        }
+        Data::Union(_) => todo!(),
+    }
+}
+
+// Chain a list of comparisons, returning the first that isn't `Equal`.
+fn lexicographic(cmps: Vec<TokenStream>) -> TokenStream {
+    cmps.into_iter()
+        .rev()
+        .fold(None, |acc, cmp| match acc {
+            None => Some(cmp),
+            Some(rest) => Some(quote! {
+                {
+                    let r = #cmp;
+                    if r == ::std::cmp::Ordering::Equal { #rest } else { r }
+                }
+            }),
+        })
+        .unwrap_or_else(|| quote!(::std::cmp::Ordering::Equal))
+}
+
+struct ArmAcc {
+    pattern_left: Vec<TokenStream>,
+    pattern_right: Vec<TokenStream>,
+    cmps: Vec<TokenStream>,
+}
+
+fn gen_match_arm<'a, I: Iterator<Item = &'a syn::Field>>(fields: I) -> ArmAcc {
+    let mut acc = ArmAcc { pattern_left: Vec::new(), pattern_right: Vec::new(), cmps: Vec::new() };
+
+    for (i, field) in fields.enumerate() {
+        let named = field.ident.is_some();
+        let name_base = match &field.ident {
+            Some(ident) => format_ident!("{}", ident),
+            None => format_ident!("v{}", i),
+        };
+        let name_1 = format_ident!("{}_1", name_base);
+        let name_2 = format_ident!("{}_2", name_base);
+
+        acc.cmps.push(quote!(::creusot_contracts::logic::OrdLogic::cmp_log(#name_1, #name_2)));
+        if named {
+            acc.pattern_left.push(quote!(#name_base: #name_1));
+            acc.pattern_right.push(quote!(#name_base: #name_2));
+        } else {
+            acc.pattern_left.push(quote!(#name_1));
+            acc.pattern_right.push(quote!(#name_2));
+        }
+    }
+
+    acc
+}
@@ -0,0 +1,106 @@
+use super::conjunction;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Fields, Index};
+
+pub fn derive_resolve(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = input.ident;
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let resolve = resolve(&name, &input.data);
+
+    let expanded = quote! {
+        #[::creusot_contracts::trusted]
+        impl #impl_generics ::creusot_contracts::resolve::Resolve for #name #ty_generics #where_clause {
+            #[::creusot_contracts::predicate]
+            fn resolve(self) -> bool {
+                #resolve
+            }
+        }
+    };
+
+    proc_macro::TokenStream::from(expanded)
+}
+
+fn resolve(base_ident: &Ident, data: &Data) -> TokenStream {
+    match *data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => {
+                let recurse = fields.named.iter().map(|f| {
+                    let name = &f.ident;
+                    quote_spanned! {f.span()=>
+                        ::creusot_contracts::resolve::Resolve::resolve(self.#name)
+                    }
+                });
+                conjunction(recurse)
+            }
+            Fields::Unnamed(ref fields) => {
+                let recurse = fields.unnamed.iter().enumerate().map(|(i, f)| {
+                    let index = Index::from(i);
+                    quote_spanned! {f.span()=>
+                        ::creusot_contracts::resolve::Resolve::resolve(self.#index)
+                    }
+                });
+                conjunction(recurse)
+            }
+            Fields::Unit => {
+                quote!(true)
+            }
+        },
+        Data::Enum(ref data) => {
+            let arms = data.variants.iter().map(|v| {
+                let ident = &v.ident;
+                match &v.fields {
+                    Fields::Named(fields) => {
+                        let arm = gen_match_arm(fields.named.iter());
+                        let pattern = arm.pattern;
+                        let body = arm.body;
+                        quote! { #base_ident::#ident{#(#pattern),* } => #body}
+                    }
+                    Fields::Unnamed(fields) => {
+                        let arm = gen_match_arm(fields.unnamed.iter());
+                        let pattern = arm.pattern;
+                        let body = arm.body;
+                        quote! { #base_ident::#ident(#(#pattern),*) => #body }
+                    }
+                    Fields::Unit => quote! {#base_ident::#ident => true},
+                }
+            });
+
+            quote! {
+                match self {
+                    #(#arms),*
+                }
+            }
+        }
+        Data::Union(_) => todo!(),
+    }
+}
+
+struct ArmAcc {
+    pattern: Vec<TokenStream>,
+    body: TokenStream,
+}
+
+fn gen_match_arm<'a, I: Iterator<Item = &'a syn::Field>>(fields: I) -> ArmAcc {
+    let mut acc = ArmAcc { pattern: Vec::new(), body: quote!(true) };
+
+    for (i, field) in fields.enumerate() {
+        let name = match &field.ident {
+            Some(ident) => format_ident!("{}", ident),
+            None => format_ident!("v{}", i),
+        };
+
+        let resolve_expr = quote!(::creusot_contracts::resolve::Resolve::resolve(#name));
+        let body = acc.body;
+        acc.body = quote! { #resolve_expr && #body };
+
+        acc.pattern.push(quote!(#name));
+    }
+
+    acc
+}
@@ -0,0 +1,70 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Index};
+
+// `ShallowModel` can only be derived for wrappers around a single field, whose model is
+// forwarded: `struct Stack<T>(Vec<T>)` has the shallow model `Seq<T>`.
+pub fn derive_shallow_model(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let (field_ty, body) = match shallow_model(&input.data) {
+        Ok(res) => res,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let expanded = quote! {
+        impl #impl_generics ::creusot_contracts::model::ShallowModel for #name #ty_generics #where_clause {
+            type ShallowModelTy = <#field_ty as ::creusot_contracts::model::ShallowModel>::ShallowModelTy;
+
+            #[::creusot_contracts::logic]
+            fn shallow_model(self) -> Self::ShallowModelTy {
+                #body
+            }
+        }
+    };
+
+    proc_macro::TokenStream::from(expanded)
+}
+
+fn shallow_model(data: &Data) -> syn::Result<(TokenStream, TokenStream)> {
+    let fields = match data {
+        Data::Struct(data) => &data.fields,
+        Data::Enum(data) => {
+            return Err(syn::Error::new(
+                data.enum_token.span(),
+                "`ShallowModel` can only be derived for structs with a single field",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                "`ShallowModel` can only be derived for structs with a single field",
+            ))
+        }
+    };
+
+    if fields.len() != 1 {
+        return Err(syn::Error::new_spanned(
+            fields,
+            "`ShallowModel` can only be derived for structs with a single field",
+        ));
+    }
+
+    let field = fields.iter().next().unwrap();
+    let ty = &field.ty;
+    let access = match &field.ident {
+        Some(name) => quote!(self.#name),
+        None => {
+            let index = Index::from(0);
+            quote!(self.#index)
+        }
+    };
+
+    let body = quote_spanned! {field.span()=>
+        ::creusot_contracts::model::ShallowModel::shallow_model(#access)
+    };
+    Ok((quote!(#ty), body))
+}
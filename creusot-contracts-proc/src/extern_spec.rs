@@ -10,7 +10,7 @@ use syn::{
     *,
 };
 
-use crate::generate_unique_ident;
+use crate::{generate_unique_ident, outcome::OutcomePost};
 
 pub struct ExternSpecs(Vec<ExternSpec>);
 
@@ -259,6 +259,15 @@ fn escape_self_in_contracts(attrs: &mut Vec<Attribute>) -> Result<()> {
                 escape_self_in_term(&mut term);
                 attr.tokens = term.into_token_stream();
             }
+            if id == "ensures_ok"
+                || id == "ensures_err"
+                || id == "ensures_some"
+                || id == "ensures_none"
+            {
+                let mut post: OutcomePost = attr.parse_args()?;
+                escape_self_in_term(&mut post.term);
+                attr.tokens = quote! { (#post) };
+            }
         }
     }
     Ok(())
@@ -367,8 +376,18 @@ fn escape_self_in_term(t: &mut Term) {
             escape_self_in_term(hyp);
             escape_self_in_term(cons);
         }
-        Term::Forall(TermForall { term, .. }) => escape_self_in_term(term),
-        Term::Exists(TermExists { term, .. }) => escape_self_in_term(term),
+        Term::Forall(TermForall { triggers, term, .. }) => {
+            for trigger in triggers {
+                trigger.terms.iter_mut().for_each(escape_self_in_term);
+            }
+            escape_self_in_term(term)
+        }
+        Term::Exists(TermExists { triggers, term, .. }) => {
+            for trigger in triggers {
+                trigger.terms.iter_mut().for_each(escape_self_in_term);
+            }
+            escape_self_in_term(term)
+        }
         Term::Absurd(TermAbsurd { .. }) => {}
         Term::Pearlite(TermPearlite { block, .. }) => escape_self_in_tblock(block),
         Term::Lit(TermLit { .. }) => {}
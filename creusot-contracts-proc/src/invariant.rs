@@ -62,52 +62,104 @@ enum LoopKind {
     Loop(ExprLoop),
 }
 
+#[derive(Debug)]
+struct Variant {
+    span: Span,
+    variant: pearlite_syn::Term,
+}
+
+impl ToTokens for Variant {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let s = self.span;
+        let var_body = pretyping::encode_term(&self.variant).unwrap();
+        tokens.extend(quote_spanned! {s=>
+            #[allow(unused_must_use)]
+            let _ = {
+                #[creusot::no_translate]
+                #[creusot::decl::spec]
+                #[creusot::spec::loop_variant]
+                ||{ ::creusot_contracts::__stubs::variant_check(#var_body) }
+            };
+        })
+    }
+}
+
+/// The loop annotation whose expansion triggered the parsing of the loop
+pub enum LoopSpec {
+    Invariant,
+    Variant,
+}
+
 pub struct Loop {
     span: Span,
     invariants: Vec<Invariant>,
+    variant: Option<Variant>,
     kind: LoopKind,
 }
 
-fn filter_invariants(attrs: &mut Vec<Attribute>) -> Vec<Attribute> {
+fn filter_loop_specs(attrs: &mut Vec<Attribute>) -> Vec<Attribute> {
     attrs
-        .drain_filter(|attr| attr.path.get_ident().map(|i| i == "invariant").unwrap_or(false))
+        .drain_filter(|attr| {
+            attr.path.get_ident().map(|i| i == "invariant" || i == "variant").unwrap_or(false)
+        })
         .collect()
 }
 
-pub fn parse(invariant: TokenStream, loopb: TokenStream) -> Result<Loop> {
+fn parse_variant(tokens: TokenStream) -> Result<Variant> {
+    let variant: pearlite_syn::Term = syn::parse2(tokens)?;
+    Ok(Variant { span: variant.span(), variant })
+}
+
+pub fn parse(kind: LoopSpec, spec: TokenStream, loopb: TokenStream) -> Result<Loop> {
     let body: Expr = syn::parse2(loopb)?;
     let span = body.span();
     let (attrs, lkind) = match body {
-        Expr::ForLoop(mut floop) => (filter_invariants(&mut floop.attrs), LoopKind::For(floop)),
-        Expr::While(mut wloop) => (filter_invariants(&mut wloop.attrs), LoopKind::While(wloop)),
-        Expr::Loop(mut lloop) => (filter_invariants(&mut lloop.attrs), LoopKind::Loop(lloop)),
+        Expr::ForLoop(mut floop) => (filter_loop_specs(&mut floop.attrs), LoopKind::For(floop)),
+        Expr::While(mut wloop) => (filter_loop_specs(&mut wloop.attrs), LoopKind::While(wloop)),
+        Expr::Loop(mut lloop) => (filter_loop_specs(&mut lloop.attrs), LoopKind::Loop(lloop)),
         _ => {
             return Err(Error::new_spanned(
                 body,
-                "invariants must be attached to either a `for`, `loop` or `while`",
+                "invariants and variants must be attached to either a `for`, `loop` or `while`",
             ))
         }
     };
 
-    let mut invariants = vec![syn::parse2(invariant)?];
+    let mut invariants = Vec::new();
+    let mut variants = Vec::new();
+    match kind {
+        LoopSpec::Invariant => invariants.push(syn::parse2(spec)?),
+        LoopSpec::Variant => variants.push(parse_variant(spec)?),
+    }
 
     for attr in attrs {
-        let i: InvParen = syn::parse2(attr.tokens)?;
-        invariants.push(i.0);
+        if attr.path.is_ident("invariant") {
+            let i: InvParen = syn::parse2(attr.tokens)?;
+            invariants.push(i.0);
+        } else {
+            let content = attr.parse_args()?;
+            variants.push(parse_variant(content)?);
+        }
+    }
+
+    if variants.len() > 1 {
+        return Err(Error::new(variants[1].span, "a loop can only have a single variant"));
     }
 
-    Ok(Loop { invariants, span, kind: lkind })
+    Ok(Loop { invariants, variant: variants.pop(), span, kind: lkind })
 }
 
 pub fn lower(loop_: Loop) -> TokenStream {
     let invariants = loop_.invariants;
+    let variant = loop_.variant;
     match loop_.kind {
-        LoopKind::For(floop) => desugar_for(invariants, floop),
+        LoopKind::For(floop) => desugar_for(invariants, variant, floop),
         LoopKind::While(l) => {
             let mut tokens = TokenStream::new();
             for i in invariants {
                 i.to_tokens(&mut tokens);
             }
+            variant.to_tokens(&mut tokens);
             let sp = loop_.span;
             quote_spanned! {sp=>{
                 #tokens
@@ -117,6 +169,7 @@ pub fn lower(loop_: Loop) -> TokenStream {
         LoopKind::Loop(l) => {
             quote! {{
                 #(#invariants;)*
+                #variant
                 #l
             }}
         }
@@ -124,7 +177,11 @@ pub fn lower(loop_: Loop) -> TokenStream {
 }
 
 // Lowers for loops to `loop` and inserts the structural invariant that we get 'for free'
-fn desugar_for(mut invariants: Vec<Invariant>, f: ExprForLoop) -> TokenStream {
+fn desugar_for(
+    mut invariants: Vec<Invariant>,
+    variant: Option<Variant>,
+    f: ExprForLoop,
+) -> TokenStream {
     let lbl = f.label;
     let pat = f.pat;
     let iter = f.expr;
@@ -160,6 +217,7 @@ fn desugar_for(mut invariants: Vec<Invariant>, f: ExprForLoop) -> TokenStream {
         let #iter_old = ghost! { #it };
         let mut #produced = ghost! { ::creusot_contracts::logic::Seq::EMPTY };
         #(#invariants;)*
+        #variant
         #(#outer)*
         #lbl
         loop {
@@ -16,6 +16,7 @@ use syn::{
 mod extern_spec;
 mod invariant;
 mod maintains;
+mod outcome;
 mod pretyping;
 
 mod derive;
@@ -319,7 +320,12 @@ pub fn variant(attr: TS1, tokens: TS1) -> TS1 {
 }
 
 fn variant_inner(attr: TS1, tokens: TS1) -> Result<TS1> {
-    let p: pearlite_syn::Term = parse(attr)?;
+    let p: pearlite_syn::Term = parse(attr.clone())?;
+
+    if let Ok(Expr::ForLoop(_) | Expr::While(_) | Expr::Loop(_)) = parse(tokens.clone()) {
+        let loop_ = invariant::parse(invariant::LoopSpec::Variant, attr.into(), tokens.into())?;
+        return Ok(invariant::lower(loop_).into());
+    }
 
     let mut f: ItemFn = parse(tokens)?;
 
@@ -388,12 +394,45 @@ pub fn ghost(assertion: TS1) -> TS1 {
                 #[creusot::no_translate]
                 #[creusot::decl::spec]
                 #[creusot::spec::ghost]
-                || { ::creusot_contracts::ghost::Ghost::new(#assertion) }
+                || { ::creusot_contracts::ghost::Ghost::new({ #assertion }) }
             )()
         }
     })
 }
 
+#[proc_macro]
+pub fn label(label: TS1) -> TS1 {
+    let label = parse_macro_input!(label as Ident);
+    let label_name = label.to_string();
+    TS1::from(quote_spanned! {label.span()=>
+        {
+            #[allow(unused_must_use)]
+            let _ = {
+                #[creusot::no_translate]
+                #[creusot::decl::spec]
+                #[creusot::spec::label=#label_name]
+                || {}
+            };
+        }
+    })
+}
+
+#[proc_macro]
+pub fn reveal(item: TS1) -> TS1 {
+    let item = parse_macro_input!(item as ExprPath);
+    TS1::from(quote_spanned! {item.span()=>
+        {
+            #[allow(unused_must_use)]
+            let _ = {
+                #[creusot::no_translate]
+                #[creusot::decl::spec]
+                #[creusot::spec::reveal]
+                || #item
+            };
+        }
+    })
+}
+
 struct LogicItem {
     vis: Visibility,
     defaultness: Option<Token![default]>,
@@ -485,6 +524,34 @@ pub fn law(_: TS1, tokens: TS1) -> TS1 {
     })
 }
 
+#[proc_macro_attribute]
+pub fn lemma(_: TS1, tokens: TS1) -> TS1 {
+    let tokens = TokenStream::from(tokens);
+    TS1::from(quote! {
+        #[creusot::decl::lemma]
+        #[::creusot_contracts::logic]
+        #tokens
+    })
+}
+
+#[proc_macro_attribute]
+pub fn open(_: TS1, tokens: TS1) -> TS1 {
+    let tokens = TokenStream::from(tokens);
+    TS1::from(quote! {
+        #[creusot::decl::open]
+        #tokens
+    })
+}
+
+#[proc_macro_attribute]
+pub fn opaque(_: TS1, tokens: TS1) -> TS1 {
+    let tokens = TokenStream::from(tokens);
+    TS1::from(quote! {
+        #[creusot::decl::opaque]
+        #tokens
+    })
+}
+
 #[proc_macro_attribute]
 pub fn predicate(_: TS1, tokens: TS1) -> TS1 {
     let pred = parse_macro_input!(tokens as LogicInput);
@@ -533,6 +600,67 @@ pub fn trusted(_: TS1, tokens: TS1) -> TS1 {
     })
 }
 
+#[proc_macro_attribute]
+pub fn ensures_ok(attr: TS1, tokens: TS1) -> TS1 {
+    outcome::ensures_outcome(outcome::Outcome::Ok, attr, tokens)
+}
+
+#[proc_macro_attribute]
+pub fn ensures_err(attr: TS1, tokens: TS1) -> TS1 {
+    outcome::ensures_outcome(outcome::Outcome::Err, attr, tokens)
+}
+
+#[proc_macro_attribute]
+pub fn ensures_some(attr: TS1, tokens: TS1) -> TS1 {
+    outcome::ensures_outcome(outcome::Outcome::Some, attr, tokens)
+}
+
+#[proc_macro_attribute]
+pub fn ensures_none(attr: TS1, tokens: TS1) -> TS1 {
+    outcome::ensures_outcome(outcome::Outcome::None, attr, tokens)
+}
+
+#[proc_macro_attribute]
+pub fn may_panic(attr: TS1, tokens: TS1) -> TS1 {
+    let mut item = parse_macro_input!(tokens as ContractSubject);
+    let term: Term = if attr.is_empty() { parse_quote!(true) } else { parse_macro_input!(attr) };
+    item.mark_unused();
+
+    let panic_name = generate_unique_ident(&item.name());
+
+    let name_tag = format!("{}", quote! { #panic_name });
+
+    match item {
+        ContractSubject::FnOrMethod(mut f) if !f.is_trait_signature() => {
+            let panic_tokens = fn_spec_item(panic_name, None, term);
+
+            if let Some(b) = f.body.as_mut() {
+                b.stmts.insert(0, Stmt::Item(Item::Verbatim(panic_tokens)));
+            }
+            TS1::from(quote! {
+              #[creusot::spec::may_panic=#name_tag]
+              #f
+            })
+        }
+        _ => TS1::from(
+            syn::Error::new(
+                Span::call_site(),
+                "`may_panic` can only be attached to functions with a body",
+            )
+            .to_compile_error(),
+        ),
+    }
+}
+
+#[proc_macro_attribute]
+pub fn terminates(_: TS1, tokens: TS1) -> TS1 {
+    let tokens = TokenStream::from(tokens);
+    TS1::from(quote! {
+        #[creusot::decl::terminates]
+        #tokens
+    })
+}
+
 #[proc_macro]
 pub fn pearlite(tokens: TS1) -> TS1 {
     let block = parse_macro_input!(tokens with TBlock::parse_within);
@@ -579,10 +707,11 @@ pub fn maintains(attr: TS1, body: TS1) -> TS1 {
 
 #[proc_macro_attribute]
 pub fn invariant(invariant: TS1, loopb: TS1) -> TS1 {
-    let loop_ = match invariant::parse(invariant.into(), loopb.into()) {
-        Ok(l) => l,
-        Err(e) => return e.to_compile_error().into(),
-    };
+    let loop_ =
+        match invariant::parse(invariant::LoopSpec::Invariant, invariant.into(), loopb.into()) {
+            Ok(l) => l,
+            Err(e) => return e.to_compile_error().into(),
+        };
 
     invariant::lower(loop_).into()
 }
@@ -598,7 +727,52 @@ pub fn derive_clone(tokens: TS1) -> TS1 {
     derive::derive_clone(tokens)
 }
 
+#[proc_macro_derive(PartialOrd)]
+pub fn derive_partial_ord(tokens: TS1) -> TS1 {
+    derive::derive_partial_ord(tokens)
+}
+
+#[proc_macro_derive(Ord)]
+pub fn derive_ord(tokens: TS1) -> TS1 {
+    derive::derive_ord(tokens)
+}
+
+#[proc_macro_derive(Default, attributes(default))]
+pub fn derive_default(tokens: TS1) -> TS1 {
+    derive::derive_default(tokens)
+}
+
+#[proc_macro_derive(Hash)]
+pub fn derive_hash(tokens: TS1) -> TS1 {
+    derive::derive_hash(tokens)
+}
+
+#[proc_macro_derive(Debug)]
+pub fn derive_debug(tokens: TS1) -> TS1 {
+    derive::derive_debug(tokens)
+}
+
 #[proc_macro_derive(Invariant)]
 pub fn derive_invariant(tokens: TS1) -> TS1 {
     derive::derive_invariant(tokens)
 }
+
+#[proc_macro_derive(DeepModel, attributes(DeepModelTy))]
+pub fn derive_deep_model(tokens: TS1) -> TS1 {
+    derive::derive_deep_model(tokens)
+}
+
+#[proc_macro_derive(ShallowModel)]
+pub fn derive_shallow_model(tokens: TS1) -> TS1 {
+    derive::derive_shallow_model(tokens)
+}
+
+#[proc_macro_derive(OrdLogic)]
+pub fn derive_ord_logic(tokens: TS1) -> TS1 {
+    derive::derive_ord_logic(tokens)
+}
+
+#[proc_macro_derive(Resolve)]
+pub fn derive_resolve(tokens: TS1) -> TS1 {
+    derive::derive_resolve(tokens)
+}
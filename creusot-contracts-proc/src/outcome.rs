@@ -0,0 +1,74 @@
+// Implementation of the `ensures_ok`, `ensures_err`, `ensures_some` and `ensures_none` macros,
+// which desugar to an `ensures` matching on the result.
+
+use pearlite_syn::*;
+use proc_macro::TokenStream as TS1;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream, Result},
+    *,
+};
+
+pub(crate) enum Outcome {
+    Ok,
+    Err,
+    Some,
+    None,
+}
+
+// Either `pattern => term`, binding the payload of the variant, or just `term`
+pub(crate) struct OutcomePost {
+    pat: Option<(Pat, Token![=>])>,
+    pub(crate) term: Term,
+}
+
+impl Parse for OutcomePost {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let fork = input.fork();
+        let pat = if fork.parse::<Pat>().is_ok() && fork.peek(Token![=>]) {
+            Some((input.parse()?, input.parse()?))
+        } else {
+            None
+        };
+
+        Ok(OutcomePost { pat, term: input.parse()? })
+    }
+}
+
+impl ToTokens for OutcomePost {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if let Some((pat, arrow)) = &self.pat {
+            pat.to_tokens(tokens);
+            arrow.to_tokens(tokens);
+        }
+        self.term.to_tokens(tokens);
+    }
+}
+
+pub(crate) fn ensures_outcome(outcome: Outcome, attr: TS1, tokens: TS1) -> TS1 {
+    let post = parse_macro_input!(attr as OutcomePost);
+    let tokens = TokenStream::from(tokens);
+
+    let pat = match &post.pat {
+        Some((pat, _)) => pat.to_token_stream(),
+        None => quote! { _ },
+    };
+    let arm = match outcome {
+        Outcome::Ok => quote! { ::std::result::Result::Ok(#pat) },
+        Outcome::Err => quote! { ::std::result::Result::Err(#pat) },
+        Outcome::Some => quote! { ::std::option::Option::Some(#pat) },
+        Outcome::None if post.pat.is_some() => {
+            return TS1::from(
+                Error::new_spanned(pat, "`ensures_none` cannot bind a value").to_compile_error(),
+            );
+        }
+        Outcome::None => quote! { ::std::option::Option::None },
+    };
+
+    let term = post.term;
+    TS1::from(quote! {
+        #[::creusot_contracts::ensures(match result { #arm => #term, _ => true })]
+        #tokens
+    })
+}
@@ -10,6 +10,7 @@ use syn::Lit;
 pub enum EncodeError {
     LocalErr,
     Unsupported(Span, String),
+    Syntax(syn::Error),
 }
 
 impl EncodeError {
@@ -22,6 +23,7 @@ impl EncodeError {
                 let msg = format!("Unsupported expression: {}", msg);
                 quote_spanned! {sp=> compile_error!(#msg) }
             }
+            Self::Syntax(err) => err.to_compile_error(),
         }
     }
 }
@@ -30,6 +32,27 @@ impl EncodeError {
 pub fn encode_term(term: &RT) -> Result<TokenStream, EncodeError> {
     let sp = term.span();
     match term {
+        // `at!(label, term)`: the value of `term` at a `label!` of the function
+        RT::Macro(m) if m.mac.path.is_ident("at") => {
+            let (label, term) = m
+                .mac
+                .parse_body_with(|input: syn::parse::ParseStream| {
+                    let label: syn::Ident = input.parse()?;
+                    let _: syn::Token![,] = input.parse()?;
+                    Ok((label, input.parse::<RT>()?))
+                })
+                .map_err(EncodeError::Syntax)?;
+            let label =
+                syn::LitStr::new(&label.to_string(), label.span().resolved_at(Span::call_site()));
+            let term = encode_term(&term)?;
+            Ok(quote_spanned! {sp=> ::creusot_contracts::__stubs::at(#label, #term) })
+        }
+        // `old!(term)`: the value of `term` before the loop, or at the start of the function
+        RT::Macro(m) if m.mac.path.is_ident("old") => {
+            let term: RT = m.mac.parse_body().map_err(EncodeError::Syntax)?;
+            let term = encode_term(&term)?;
+            Ok(quote_spanned! {sp=> ::creusot_contracts::__stubs::at_entry(#term) })
+        }
         // Macros could contain further pearlite expressions..
         RT::Macro(m) => Ok(quote_spanned! {sp=> #m}),
         RT::Array(_) => Err(EncodeError::Unsupported(term.span(), "Array".into())),
@@ -219,29 +242,23 @@ pub fn encode_term(term: &RT) -> Result<TokenStream, EncodeError> {
                 ::creusot_contracts::__stubs::implication(#hyp, #cons)
             })
         }
-        RT::Forall(TermForall { args, term, .. }) => {
-            let mut ts = encode_term(term)?;
-            for arg in args {
-                ts = quote! {
-                    ::creusot_contracts::__stubs::forall(
-                        #[creusot::no_translate]
-                        |#arg|{ #ts }
-                    )
-                }
-            }
-            Ok(ts)
+        RT::Forall(TermForall { args, triggers, term, .. }) => {
+            let ts = encode_triggers(triggers, encode_term(term)?)?;
+            Ok(quote! {
+                ::creusot_contracts::__stubs::forall(
+                    #[creusot::no_translate]
+                    |#args|{ #ts }
+                )
+            })
         }
-        RT::Exists(TermExists { args, term, .. }) => {
-            let mut ts = encode_term(term)?;
-            for arg in args {
-                ts = quote! {
-                    ::creusot_contracts::__stubs::exists(
-                        #[creusot::no_translate]
-                        |#arg|{ #ts }
-                    )
-                }
-            }
-            Ok(ts)
+        RT::Exists(TermExists { args, triggers, term, .. }) => {
+            let ts = encode_triggers(triggers, encode_term(term)?)?;
+            Ok(quote! {
+                ::creusot_contracts::__stubs::exists(
+                    #[creusot::no_translate]
+                    |#args|{ #ts }
+                )
+            })
         }
         RT::Absurd(_) => Ok(quote_spanned! {sp=> ::creusot_contracts::__stubs::abs() }),
         RT::Pearlite(term) => Ok(quote_spanned! {sp=> #term }),
@@ -295,6 +312,16 @@ fn encode_arm(arm: &TermArm) -> Result<TokenStream, EncodeError> {
     Ok(quote! { #pat  => #body #comma })
 }
 
+// Each trigger wraps the body of the quantifier, so that they can be found when translating it
+fn encode_triggers(triggers: &[Trigger], body: TokenStream) -> Result<TokenStream, EncodeError> {
+    triggers.iter().rev().try_fold(body, |body, trigger| {
+        let terms = trigger.terms.iter().map(encode_term).collect::<Result<Vec<_>, _>>()?;
+        Ok(quote_spanned! {trigger.span()=>
+            ::creusot_contracts::__stubs::trigger((#(#terms,)*), #body)
+        })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -309,6 +336,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn encode_at() {
+        let term: Term = syn::parse_str("at!(start, x) + old!(x)").unwrap();
+
+        assert_eq!(
+            format!("{}", encode_term(&term).unwrap()),
+            ":: creusot_contracts :: __stubs :: at (\"start\" , x) + :: creusot_contracts :: __stubs :: at_entry (x)"
+        );
+    }
+
     #[test]
     fn encode_fin() {
         let term: Term = syn::parse_str("^ x").unwrap();
@@ -351,6 +388,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn encode_multiple_binders() {
+        let term: Term = syn::parse_str("forall<x:Int, (y, z):(Int, Int)> true").unwrap();
+        assert_eq!(
+            format!("{}", encode_term(&term).unwrap()),
+            ":: creusot_contracts :: __stubs :: forall (# [creusot :: no_translate] | x : Int , (y , z) : (Int , Int) | { true })"
+        );
+    }
+
+    #[test]
+    fn encode_trigger() {
+        let term: Term = syn::parse_str("forall<x:Int> #[trigger(f(x))] f(x)").unwrap();
+        assert_eq!(
+            format!("{}", encode_term(&term).unwrap()),
+            ":: creusot_contracts :: __stubs :: forall (# [creusot :: no_translate] | x : Int | { :: creusot_contracts :: __stubs :: trigger ((f (x) ,) , f (x)) })"
+        );
+    }
+
     #[test]
     fn encode_exists() {
         let term: Term = syn::parse_str("exists<x:Int> x == x").unwrap();
@@ -0,0 +1,14 @@
+//! Derives of std traits whose implementations are given a specification, used in place of the
+//! derives of std as `#[derive(creusot_contracts::derive::Ord)]`.
+//! Without Creusot, these are the derives of std.
+
+#[cfg(creusot)]
+pub use creusot_contracts_proc::{Debug, Default, Hash, Ord, PartialOrd};
+
+#[cfg(not(creusot))]
+pub use ::std::{
+    cmp::{Ord, PartialOrd},
+    default::Default,
+    fmt::Debug,
+    hash::Hash,
+};
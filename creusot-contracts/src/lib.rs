@@ -6,7 +6,7 @@
     feature(print_internals, fmt_internals, fmt_helpers_for_derive)
 )]
 #![cfg_attr(feature = "typechecker", feature(rustc_private), feature(box_patterns))]
-#![feature(step_trait, allocator_api, unboxed_closures, tuple_trait)]
+#![feature(step_trait, allocator_api, unboxed_closures, tuple_trait, try_trait_v2)]
 #![cfg_attr(not(creusot), feature(rustc_attrs))]
 
 extern crate self as creusot_contracts;
@@ -21,6 +21,27 @@ mod macros {
     #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
     pub use creusot_contracts_proc::ensures;
 
+    /// A post-condition holding when the function returns `Ok`, written `#[ensures_ok(v => ...)]`
+    /// to bind the returned value
+    #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
+    pub use creusot_contracts_proc::ensures_ok;
+
+    /// A post-condition holding when the function returns `Err`, written `#[ensures_err(e => ...)]`
+    /// to bind the returned error
+    #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
+    pub use creusot_contracts_proc::ensures_err;
+
+    /// A post-condition holding when the function returns `Some`, written `#[ensures_some(v => ...)]`
+    /// to bind the returned value
+    #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
+    pub use creusot_contracts_proc::ensures_some;
+
+    /// A post-condition holding when the function returns `None`
+    #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
+    pub use creusot_contracts_proc::ensures_none;
+
+    /// A block of ghost code, evaluating to a `Ghost` value. It may only call logical functions,
+    /// and may assign the ghost variables of the enclosing function
     #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
     pub use creusot_contracts_proc::ghost;
 
@@ -35,6 +56,16 @@ mod macros {
     #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
     pub use creusot_contracts_proc::law;
 
+    /// Names a program point, whose values can be referred to as `at!(label, expr)` in loop
+    /// invariants and `proof_assert!`
+    #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
+    pub use creusot_contracts_proc::label;
+
+    /// Declares a logical function returning `()` as a lemma: its postcondition is proved once
+    /// (by induction if it has a `#[variant]`) and may then be used by calling it
+    #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
+    pub use creusot_contracts_proc::lemma;
+
     /// Declare a function as being a logical function, this declaration must be pure and
     /// total. It cannot be called from Rust programs as it is *ghost*, in exchange it can
     /// use logical operations and syntax with the help of the [pearlite] macro.
@@ -47,6 +78,19 @@ mod macros {
     #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
     pub use creusot_contracts_proc::predicate;
 
+    /// Makes the body of a logical function visible everywhere it is used. This is the default
+    #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
+    pub use creusot_contracts_proc::open;
+
+    /// Hides the body of a logical function outside of the module defining it, where only its
+    /// contract is known, unless it is revealed with [reveal]
+    #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
+    pub use creusot_contracts_proc::opaque;
+
+    /// Makes the body of an `#[opaque]` logical function visible in the enclosing function
+    #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
+    pub use creusot_contracts_proc::reveal;
+
     /// Inserts a *logical* assertion into the code. This assertion will not be checked at runtime
     /// but only during proofs. However, it has access to the ghost context and can use logical operations
     /// and syntax.
@@ -63,6 +107,16 @@ mod macros {
     #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
     pub use creusot_contracts_proc::variant;
 
+    /// Requires a program function to terminate: its loops and recursive calls must carry a
+    /// variant, and it may only call terminating functions
+    #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
+    pub use creusot_contracts_proc::terminates;
+
+    /// Allows a program function to panic, provided the given condition (by default `true`) holds
+    /// on its arguments. Functions without it are proved not to panic
+    #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
+    pub use creusot_contracts_proc::may_panic;
+
     /// Enables Pearlite syntax, granting access to Pearlite specific operators and syntax
     #[cfg_attr(feature = "contracts", deprecated("the `contracts` feature is no longer needed. Use `cfg(creusot)` instead to toggle behavior"))]
     pub use creusot_contracts_proc::pearlite;
@@ -90,6 +144,23 @@ mod macros {
     /// A post-condition of a function or trait item
     pub use creusot_contracts_dummy::ensures;
 
+    /// A post-condition holding when the function returns `Ok`, written `#[ensures_ok(v => ...)]`
+    /// to bind the returned value
+    pub use creusot_contracts_dummy::ensures_ok;
+
+    /// A post-condition holding when the function returns `Err`, written `#[ensures_err(e => ...)]`
+    /// to bind the returned error
+    pub use creusot_contracts_dummy::ensures_err;
+
+    /// A post-condition holding when the function returns `Some`, written `#[ensures_some(v => ...)]`
+    /// to bind the returned value
+    pub use creusot_contracts_dummy::ensures_some;
+
+    /// A post-condition holding when the function returns `None`
+    pub use creusot_contracts_dummy::ensures_none;
+
+    /// A block of ghost code, evaluating to a `Ghost` value. It may only call logical functions,
+    /// and may assign the ghost variables of the enclosing function
     pub use creusot_contracts_dummy::ghost;
 
     /// A loop invariant
@@ -101,6 +172,14 @@ mod macros {
     /// trait item is used in a function
     pub use creusot_contracts_dummy::law;
 
+    /// Names a program point, whose values can be referred to as `at!(label, expr)` in loop
+    /// invariants and `proof_assert!`
+    pub use creusot_contracts_dummy::label;
+
+    /// Declares a logical function returning `()` as a lemma: its postcondition is proved once
+    /// (by induction if it has a `#[variant]`) and may then be used by calling it
+    pub use creusot_contracts_dummy::lemma;
+
     /// Declare a function as being a logical function, this declaration must be pure and
     /// total. It cannot be called from Rust programs as it is *ghost*, in exchange it can
     /// use logical operations and syntax with the help of the [pearlite] macro.
@@ -111,6 +190,16 @@ mod macros {
     /// use logical operations and syntax with the help of the [pearlite] macro.
     pub use creusot_contracts_dummy::predicate;
 
+    /// Makes the body of a logical function visible everywhere it is used. This is the default
+    pub use creusot_contracts_dummy::open;
+
+    /// Hides the body of a logical function outside of the module defining it, where only its
+    /// contract is known, unless it is revealed with [reveal]
+    pub use creusot_contracts_dummy::opaque;
+
+    /// Makes the body of an `#[opaque]` logical function visible in the enclosing function
+    pub use creusot_contracts_dummy::reveal;
+
     /// Inserts a *logical* assertion into the code. This assertion will not be checked at runtime
     /// but only during proofs. However, it has access to the ghost context and can use logical operations
     /// and syntax.
@@ -124,6 +213,14 @@ mod macros {
     /// The variant must be an expression which returns a type implementing [WellFounded]
     pub use creusot_contracts_dummy::variant;
 
+    /// Requires a program function to terminate: its loops and recursive calls must carry a
+    /// variant, and it may only call terminating functions
+    pub use creusot_contracts_dummy::terminates;
+
+    /// Allows a program function to panic, provided the given condition (by default `true`) holds
+    /// on its arguments. Functions without it are proved not to panic
+    pub use creusot_contracts_dummy::may_panic;
+
     /// Enables Pearlite syntax, granting access to Pearlite specific operators and syntax
     pub use creusot_contracts_dummy::pearlite;
 
@@ -165,6 +262,7 @@ pub mod ghost {
     }
 }
 
+pub mod derive;
 pub mod invariant;
 pub mod model;
 pub mod resolve;
@@ -4,6 +4,7 @@ mod fset;
 mod int;
 mod mapping;
 mod ord;
+mod real;
 mod seq;
 mod set;
 
@@ -11,5 +12,6 @@ pub use fset::FSet;
 pub use int::Int;
 pub use mapping::Mapping;
 pub use ord::OrdLogic;
+pub use real::Real;
 pub use seq::Seq;
 pub use set::Set;
@@ -1,4 +1,8 @@
-use crate::{std::cmp::Ordering, *};
+use crate::{logic::Real, std::cmp::Ordering, *};
+
+/// Derives a lexicographic [OrdLogic]: variants are ordered by declaration order, and fields
+/// are compared from first to last.
+pub use creusot_contracts_proc::OrdLogic;
 
 #[allow(unused)]
 pub trait OrdLogic {
@@ -68,6 +72,9 @@ pub trait OrdLogic {
 
 macro_rules! ord_logic_impl {
     ($t:ty) => {
+        ord_logic_impl!($t, "int.Int");
+    };
+    ($t:ty, $theory:literal) => {
         impl OrdLogic for $t {
             #[logic]
             fn cmp_log(self, o: Self) -> Ordering {
@@ -82,28 +89,28 @@ macro_rules! ord_logic_impl {
 
             #[trusted]
             #[predicate]
-            #[creusot::builtins = "int.Int.(<=)"]
+            #[creusot::builtins = concat!($theory, ".(<=)")]
             fn le_log(self, _: Self) -> bool {
                 true
             }
 
             #[trusted]
             #[predicate]
-            #[creusot::builtins = "int.Int.(<)"]
+            #[creusot::builtins = concat!($theory, ".(<)")]
             fn lt_log(self, _: Self) -> bool {
                 true
             }
 
             #[trusted]
             #[predicate]
-            #[creusot::builtins = "int.Int.(>=)"]
+            #[creusot::builtins = concat!($theory, ".(>=)")]
             fn ge_log(self, _: Self) -> bool {
                 true
             }
 
             #[trusted]
             #[predicate]
-            #[creusot::builtins = "int.Int.(>)"]
+            #[creusot::builtins = concat!($theory, ".(>)")]
             fn gt_log(self, _: Self) -> bool {
                 true
             }
@@ -157,6 +164,7 @@ macro_rules! ord_logic_impl {
 }
 
 ord_logic_impl!(Int);
+ord_logic_impl!(Real, "real.Real");
 
 ord_logic_impl!(u8);
 ord_logic_impl!(u16);
@@ -0,0 +1,95 @@
+use crate::{
+    std::ops::{Add, Div, Mul, Neg, Sub},
+    *,
+};
+
+/// Mathematical real numbers, mapped to Why3's `real` type.
+#[cfg_attr(creusot, creusot::builtins = "real.Real.real")]
+pub struct Real(*mut ());
+
+impl Real {
+    #[trusted]
+    #[logic]
+    #[creusot::builtins = "real.FromInt.from_int"]
+    pub fn from_int(_: Int) -> Real {
+        absurd
+    }
+
+    #[trusted]
+    #[logic]
+    #[creusot::builtins = "real.Square.sqrt"]
+    pub fn sqrt(self) -> Real {
+        absurd
+    }
+
+    #[trusted]
+    #[logic]
+    #[creusot::builtins = "real.Abs.abs"]
+    pub fn abs(self) -> Real {
+        absurd
+    }
+
+    #[trusted]
+    #[logic]
+    #[creusot::builtins = "real.MinMax.max"]
+    pub fn max(self, _: Real) -> Real {
+        absurd
+    }
+
+    #[trusted]
+    #[logic]
+    #[creusot::builtins = "real.MinMax.min"]
+    pub fn min(self, _: Real) -> Real {
+        absurd
+    }
+}
+
+#[cfg(creusot)]
+impl Add<Real> for Real {
+    type Output = Real;
+    #[creusot::no_translate]
+    #[creusot::builtins = "real.Real.(+)"]
+    fn add(self, _: Real) -> Self {
+        panic!()
+    }
+}
+
+#[cfg(creusot)]
+impl Sub<Real> for Real {
+    type Output = Real;
+    #[creusot::no_translate]
+    #[creusot::builtins = "real.Real.(-)"]
+    fn sub(self, _: Real) -> Self {
+        panic!()
+    }
+}
+
+#[cfg(creusot)]
+impl Mul<Real> for Real {
+    type Output = Real;
+    #[creusot::no_translate]
+    #[creusot::builtins = "real.Real.( * )"]
+    fn mul(self, _: Real) -> Self {
+        panic!()
+    }
+}
+
+#[cfg(creusot)]
+impl Div<Real> for Real {
+    type Output = Real;
+    #[creusot::no_translate]
+    #[creusot::builtins = "real.Real.(/)"]
+    fn div(self, _: Real) -> Self {
+        panic!()
+    }
+}
+
+#[cfg(creusot)]
+impl Neg for Real {
+    type Output = Real;
+    #[creusot::no_translate]
+    #[creusot::builtins = "real.Real.(-_)"]
+    fn neg(self) -> Self {
+        panic!()
+    }
+}
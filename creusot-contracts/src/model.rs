@@ -3,6 +3,15 @@ use crate::*;
 #[cfg(creusot)]
 use ::std::alloc::Allocator;
 
+/// Derives a [DeepModel] by generating a type `<Name>DeepModel` of the same shape, whose
+/// fields are the deep models of the original fields.
+/// An existing type of the same shape can be used instead with `#[DeepModelTy = "Ty"]`.
+pub use creusot_contracts_proc::DeepModel;
+
+/// Derives a [ShallowModel] for a struct with a single field, by forwarding to the
+/// shallow model of that field.
+pub use creusot_contracts_proc::ShallowModel;
+
 /// The shallow model of a type is typically used to specify a data
 /// structure. This kind of model is mostly useful for notation purposes,
 /// because this trait is linked to the @ notation of pearlite.
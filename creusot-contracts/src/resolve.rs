@@ -1,5 +1,8 @@
 use crate::*;
 
+/// Derives [Resolve] as the conjunction of the resolution of every field.
+pub use creusot_contracts_proc::Resolve;
+
 #[cfg_attr(creusot, rustc_diagnostic_item = "creusot_resolve")]
 #[trusted]
 pub trait Resolve {
@@ -3,9 +3,12 @@ pub use ::std::*;
 pub mod boxed;
 pub mod clone;
 pub mod cmp;
+pub mod collections;
+pub mod convert;
 pub mod default;
 pub mod deque;
 pub mod fmt;
+pub mod hash;
 pub mod iter;
 pub mod mem;
 pub mod num;
@@ -13,5 +16,6 @@ pub mod ops;
 pub mod option;
 pub mod result;
 pub mod slice;
+pub mod str;
 pub mod time;
 pub mod vec;
@@ -0,0 +1,207 @@
+use crate::{
+    invariant::Invariant,
+    logic::Mapping,
+    std::{
+        alloc::Allocator,
+        borrow::Borrow,
+        hash::{BuildHasher, Hash},
+    },
+    *,
+};
+pub use ::std::collections::*;
+
+/// `visited` are the entries of `start` which are not in `end`, each produced exactly once
+#[predicate]
+fn map_produces<'a, K: DeepModel, V>(
+    start: Mapping<K::DeepModelTy, Option<V>>,
+    visited: Seq<(&'a K, &'a V)>,
+    end: Mapping<K::DeepModelTy, Option<V>>,
+) -> bool {
+    pearlite! {
+        (forall<k: K::DeepModelTy> end.get(k) == None || end.get(k) == start.get(k)) &&
+        (forall<i: Int> 0 <= i && i < visited.len() ==>
+            start.get(visited[i].0.deep_model()) == Some(*visited[i].1) &&
+            end.get(visited[i].0.deep_model()) == None) &&
+        (forall<i: Int, j: Int> 0 <= i && i < j && j < visited.len() ==>
+            visited[i].0.deep_model() != visited[j].0.deep_model()) &&
+        forall<k: K::DeepModelTy> start.get(k) != None ==>
+            end.get(k) == start.get(k) ||
+            exists<i: Int> 0 <= i && i < visited.len() && visited[i].0.deep_model() == k
+    }
+}
+
+impl<K: DeepModel, V, S> ShallowModel for HashMap<K, V, S> {
+    type ShallowModelTy = Mapping<K::DeepModelTy, Option<V>>;
+
+    #[logic]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        pearlite! { absurd }
+    }
+}
+
+impl<K: DeepModel, V, S> IntoIterator for &HashMap<K, V, S> {
+    #[predicate]
+    fn into_iter_pre(self) -> bool {
+        pearlite! { true }
+    }
+
+    #[predicate]
+    fn into_iter_post(self, res: Self::IntoIter) -> bool {
+        pearlite! { @self == @res }
+    }
+}
+
+impl<'a, K: DeepModel, V> ShallowModel for hash_map::Iter<'a, K, V> {
+    type ShallowModelTy = Mapping<K::DeepModelTy, Option<V>>;
+
+    #[logic]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        pearlite! { absurd }
+    }
+}
+
+impl<'a, K, V> Invariant for hash_map::Iter<'a, K, V> {}
+
+impl<'a, K: DeepModel, V> Iterator for hash_map::Iter<'a, K, V> {
+    #[predicate]
+    fn completed(&mut self) -> bool {
+        pearlite! { self.resolve() && forall<k: K::DeepModelTy> (@*self).get(k) == None }
+    }
+
+    #[predicate]
+    fn produces(self, visited: Seq<Self::Item>, tl: Self) -> bool {
+        pearlite! { map_produces(@self, visited, @tl) }
+    }
+
+    #[law]
+    #[ensures(a.produces(Seq::EMPTY, a))]
+    fn produces_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+impl<K: DeepModel, V, A: Allocator + Clone> ShallowModel for BTreeMap<K, V, A> {
+    type ShallowModelTy = Mapping<K::DeepModelTy, Option<V>>;
+
+    #[logic]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        pearlite! { absurd }
+    }
+}
+
+impl<K: DeepModel, V, A: Allocator + Clone> IntoIterator for &BTreeMap<K, V, A> {
+    #[predicate]
+    fn into_iter_pre(self) -> bool {
+        pearlite! { true }
+    }
+
+    #[predicate]
+    fn into_iter_post(self, res: Self::IntoIter) -> bool {
+        pearlite! { @self == @res }
+    }
+}
+
+impl<'a, K: DeepModel, V> ShallowModel for btree_map::Iter<'a, K, V> {
+    type ShallowModelTy = Mapping<K::DeepModelTy, Option<V>>;
+
+    #[logic]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        pearlite! { absurd }
+    }
+}
+
+impl<'a, K, V> Invariant for btree_map::Iter<'a, K, V> {}
+
+impl<'a, K: DeepModel, V> Iterator for btree_map::Iter<'a, K, V> {
+    #[predicate]
+    fn completed(&mut self) -> bool {
+        pearlite! { self.resolve() && forall<k: K::DeepModelTy> (@*self).get(k) == None }
+    }
+
+    #[predicate]
+    fn produces(self, visited: Seq<Self::Item>, tl: Self) -> bool {
+        pearlite! { map_produces(@self, visited, @tl) }
+    }
+
+    #[law]
+    #[ensures(a.produces(Seq::EMPTY, a))]
+    fn produces_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+extern_spec! {
+    mod std {
+        mod collections {
+            impl<K, V> HashMap<K, V> {
+                #[ensures(forall<k: K::DeepModelTy> (@result).get(k) == None)]
+                fn new() -> Self
+                    where K: DeepModel;
+            }
+
+            impl<K: Eq + Hash, V, S: BuildHasher> HashMap<K, V, S> {
+                #[ensures(result == (@self).get(k.deep_model()))]
+                #[ensures(@^self == (@self).set(k.deep_model(), Some(v)))]
+                fn insert(&mut self, k: K, v: V) -> Option<V>
+                    where K: DeepModel;
+
+                #[ensures(match result {
+                    Some(v) => (@self).get(k.deep_model()) == Some(*v),
+                    None => (@self).get(k.deep_model()) == None,
+                })]
+                fn get<'a, Q: ?Sized>(&'a self, k: &Q) -> Option<&'a V>
+                    where K: Borrow<Q> + DeepModel, Q: Hash + Eq + DeepModel<DeepModelTy = K::DeepModelTy>;
+
+                #[ensures(result == ((@self).get(k.deep_model()) != None))]
+                fn contains_key<Q: ?Sized>(&self, k: &Q) -> bool
+                    where K: Borrow<Q> + DeepModel, Q: Hash + Eq + DeepModel<DeepModelTy = K::DeepModelTy>;
+            }
+
+            impl<K, V, S> HashMap<K, V, S> {
+                #[ensures(@result == @self)]
+                fn iter(&self) -> hash_map::Iter<'_, K, V>
+                    where K: DeepModel;
+            }
+
+            impl<K, V> BTreeMap<K, V> {
+                #[ensures(forall<k: K::DeepModelTy> (@result).get(k) == None)]
+                fn new() -> Self
+                    where K: DeepModel;
+            }
+
+            impl<K, V, A: Allocator + Clone> BTreeMap<K, V, A> {
+                #[ensures(result == (@self).get(key.deep_model()))]
+                #[ensures(@^self == (@self).set(key.deep_model(), Some(value)))]
+                fn insert(&mut self, key: K, value: V) -> Option<V>
+                    where K: Ord + DeepModel;
+
+                #[ensures(match result {
+                    Some(v) => (@self).get(key.deep_model()) == Some(*v),
+                    None => (@self).get(key.deep_model()) == None,
+                })]
+                fn get<'a, Q: ?Sized>(&'a self, key: &Q) -> Option<&'a V>
+                    where K: Borrow<Q> + Ord + DeepModel, Q: Ord + DeepModel<DeepModelTy = K::DeepModelTy>;
+
+                #[ensures(result == ((@self).get(key.deep_model()) != None))]
+                fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+                    where K: Borrow<Q> + Ord + DeepModel, Q: Ord + DeepModel<DeepModelTy = K::DeepModelTy>;
+
+                #[ensures(@result == @self)]
+                fn iter(&self) -> btree_map::Iter<'_, K, V>
+                    where K: DeepModel;
+            }
+        }
+    }
+}
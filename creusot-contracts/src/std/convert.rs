@@ -0,0 +1,21 @@
+use crate::*;
+pub use ::std::convert::*;
+
+// `From` impls are specified by the contract of their own `from`, which `Into::into` and the `?`
+// operator on `Result` use as well.
+extern_spec! {
+    mod std {
+        mod convert {
+            trait From<T> {
+                fn from(value: T) -> Self;
+            }
+        }
+    }
+}
+
+extern_spec! {
+    impl<T> From<T> for T {
+        #[ensures(result == t)]
+        fn from(t: T) -> T;
+    }
+}
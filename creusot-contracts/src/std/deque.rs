@@ -1,5 +1,6 @@
-use crate::{std::alloc::Allocator, *};
-pub use ::std::collections::VecDeque;
+use crate::{invariant::Invariant, std::alloc::Allocator, *};
+use ::std::collections::vec_deque::IntoIter;
+pub use ::std::collections::{vec_deque::Iter, VecDeque};
 
 impl<T, A: Allocator> ShallowModel for VecDeque<T, A> {
     type ShallowModelTy = Seq<T>;
@@ -68,7 +69,118 @@ extern_spec! {
 
                 #[ensures(@^self == (@self).push(value))]
                 fn push_back(&mut self, value: T);
+
+                #[ensures((@result).len() == (@self).len())]
+                #[ensures(forall<i: Int> 0 <= i && i < (@self).len() ==> *(@result)[i] == (@self)[i])]
+                fn iter(&self) -> Iter<'_, T>;
             }
         }
     }
 }
+
+impl<T, A: Allocator> IntoIterator for VecDeque<T, A> {
+    #[predicate]
+    fn into_iter_pre(self) -> bool {
+        pearlite! { true }
+    }
+
+    #[predicate]
+    fn into_iter_post(self, res: Self::IntoIter) -> bool {
+        pearlite! { @self == @res }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a VecDeque<T, A> {
+    #[predicate]
+    fn into_iter_pre(self) -> bool {
+        pearlite! { true }
+    }
+
+    #[predicate]
+    fn into_iter_post(self, res: Self::IntoIter) -> bool {
+        pearlite! {
+            (@res).len() == (@self).len() &&
+            forall<i: Int> 0 <= i && i < (@self).len() ==> *(@res)[i] == (@self)[i]
+        }
+    }
+}
+
+impl<T, A: Allocator> ShallowModel for IntoIter<T, A> {
+    type ShallowModelTy = Seq<T>;
+
+    #[logic]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        absurd
+    }
+}
+
+#[trusted]
+impl<T, A: Allocator> Resolve for IntoIter<T, A> {
+    #[predicate]
+    fn resolve(self) -> bool {
+        pearlite! { forall<i: Int> 0 <= i && i < (@self).len() ==> (@self)[i].resolve() }
+    }
+}
+
+impl<T, A: Allocator> Invariant for IntoIter<T, A> {}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    #[predicate]
+    fn completed(&mut self) -> bool {
+        pearlite! { self.resolve() && @self == Seq::EMPTY }
+    }
+
+    #[predicate]
+    fn produces(self, visited: Seq<T>, rhs: Self) -> bool {
+        pearlite! {
+            @self == visited.concat(@rhs)
+        }
+    }
+
+    #[law]
+    #[ensures(a.produces(Seq::EMPTY, a))]
+    fn produces_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<T>, b: Self, bc: Seq<T>, c: Self) {}
+}
+
+impl<'a, T> ShallowModel for Iter<'a, T> {
+    type ShallowModelTy = Seq<&'a T>;
+
+    #[logic]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        absurd
+    }
+}
+
+impl<'a, T> Invariant for Iter<'a, T> {}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    #[predicate]
+    fn completed(&mut self) -> bool {
+        pearlite! { self.resolve() && @*self == Seq::EMPTY }
+    }
+
+    #[predicate]
+    fn produces(self, visited: Seq<Self::Item>, tl: Self) -> bool {
+        pearlite! {
+            @self == visited.concat(@tl)
+        }
+    }
+
+    #[law]
+    #[ensures(a.produces(Seq::EMPTY, a))]
+    fn produces_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
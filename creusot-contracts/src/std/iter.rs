@@ -1,23 +1,42 @@
 use crate::{invariant::Invariant, *};
 pub use ::std::iter::*;
 
+mod chain;
 mod cloned;
 mod copied;
 mod empty;
 mod enumerate;
+mod filter;
+mod fold;
+mod fuse;
+mod map;
 mod map_inv;
 mod once;
+mod peekable;
 mod range;
 mod repeat;
+mod rev;
 mod skip;
+mod step_by;
+mod sum;
 mod take;
+mod zip;
 
+pub use chain::ChainExt;
 pub use cloned::ClonedExt;
 pub use copied::CopiedExt;
 pub use enumerate::EnumerateExt;
+pub use filter::FilterExt;
+pub use fuse::{FuseExt, FusedIterator};
+pub use map::MapExt;
 pub use map_inv::MapInv;
+pub use peekable::PeekableExt;
+pub use rev::RevExt;
 pub use skip::SkipExt;
+pub use step_by::StepByExt;
+pub use sum::Sum;
 pub use take::TakeExt;
+pub use zip::ZipExt;
 
 pub trait Iterator: ::std::iter::Iterator + Invariant {
     #[predicate]
@@ -49,6 +68,21 @@ pub trait Iterator: ::std::iter::Iterator + Invariant {
     }
 }
 
+pub trait DoubleEndedIterator: ::std::iter::DoubleEndedIterator + Iterator {
+    #[predicate]
+    fn produces_back(self, visited: Seq<Self::Item>, _o: Self) -> bool;
+
+    #[law]
+    #[ensures(a.produces_back(Seq::EMPTY, a))]
+    fn produces_back_refl(a: Self);
+
+    #[law]
+    #[requires(a.produces_back(ab, b))]
+    #[requires(b.produces_back(bc, c))]
+    #[ensures(a.produces_back(ab.concat(bc), c))]
+    fn produces_back_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self);
+}
+
 pub trait IntoIterator: ::std::iter::IntoIterator {
     #[predicate]
     fn into_iter_pre(self) -> bool {
@@ -107,6 +141,97 @@ extern_spec! {
                 #[ensures(result.iter() == self && result.n() == 0)]
                 fn enumerate(self) -> Enumerate<Self>;
 
+                #[requires(forall<e: Self_::Item, i: Self_> self.produces(Seq::singleton(e), i) ==> f.precondition((e,)))]
+                #[requires(map::reinitialize::<Self_, B, F>())]
+                #[requires(map::preservation::<Self_, B, F>(self, f))]
+                #[ensures(result.iter() == self && result.func() == f)]
+                fn map<B, F>(self, f: F) -> Map<Self, F>
+                    where F: FnMut(Self::Item) -> B;
+
+                #[requires(filter::immutable::<&Self_::Item, P>(predicate))]
+                #[requires(filter::no_precondition::<&Self_::Item, P>(predicate))]
+                #[requires(filter::precise::<&Self_::Item, P>(predicate))]
+                #[ensures(result.iter() == self && result.func() == predicate)]
+                fn filter<P>(self, predicate: P) -> Filter<Self, P>
+                    where P: FnMut(&Self::Item) -> bool;
+
+                #[requires(other.into_iter_pre())]
+                #[ensures(result.itera() == self && other.into_iter_post(result.iterb()))]
+                fn zip<U>(self, other: U) -> Zip<Self, U::IntoIter>
+                    where U: IntoIterator, U::IntoIter: Iterator + Invariant;
+
+                #[requires(other.into_iter_pre())]
+                #[ensures(result.a() == Some(self))]
+                #[ensures(exists<b: U::IntoIter> result.b() == Some(b) && other.into_iter_post(b))]
+                fn chain<U>(self, other: U) -> Chain<Self, U::IntoIter>
+                    where U: IntoIterator<Item = Self::Item>, U::IntoIter: Iterator + Invariant;
+
+                #[ensures(result.iter() == self)]
+                fn rev(self) -> Rev<Self>
+                    where Self: DoubleEndedIterator;
+
+                #[requires(@step != 0)]
+                #[ensures(result.iter() == self && result.step() == @step && result.first_take())]
+                fn step_by(self, step: usize) -> StepBy<Self>;
+
+                #[ensures(result.iter() == self && result.peeked() == None)]
+                fn peekable(self) -> Peekable<Self>;
+
+                #[ensures(result.iter() == Some(self))]
+                fn fuse(self) -> Fuse<Self>;
+
+                #[requires(forall<prod: Seq<Self_::Item>, x: Self_::Item, i: Self_, acc: B, g: F>
+                    self.produces(prod.push(x), i) && fold::reaches(f, init, prod, acc, g) ==> g.precondition((acc, x)))]
+                #[ensures(exists<done_ : &mut Self_, prod: Seq<Self_::Item>, g: F> (^done_).resolve() && done_.completed() &&
+                    self.produces(prod, *done_) && fold::reaches(f, init, prod, result, g) && g.resolve())]
+                fn fold<B, F>(self, init: B, f: F) -> B
+                    where F: FnMut(B, Self::Item) -> B;
+
+                #[requires(forall<prod: Seq<Self_::Item>, i: Self_> self.produces(prod, i) ==> prod.len() <= @usize::MAX)]
+                #[ensures(exists<done_ : &mut Self_, prod: Seq<Self_::Item>> (^done_).resolve() && done_.completed() &&
+                    self.produces(prod, *done_) && @result == prod.len())]
+                fn count(self) -> usize;
+
+                #[requires(forall<prod: Seq<Self_::Item>, i: Self_> self.produces(prod, i) ==> S::sum_pre(prod))]
+                #[ensures(exists<done_ : &mut Self_, prod: Seq<Self_::Item>> (^done_).resolve() && done_.completed() &&
+                    self.produces(prod, *done_) && S::sum_post(prod, result))]
+                fn sum<S>(self) -> S
+                    where S: Sum<Self::Item>;
+
+                #[requires(filter::immutable(f) && filter::no_precondition(f) && filter::precise(f))]
+                #[ensures(if result {
+                    exists<prod: Seq<Self_::Item>, x: Self_::Item> (*self).produces(prod.push(x), ^self) && filter::accepts(f, x) &&
+                        forall<i: Int> 0 <= i && i < prod.len() ==> !filter::accepts(f, prod[i])
+                } else {
+                    exists<done_ : &mut Self_, prod: Seq<Self_::Item>> (*self).produces(prod, *done_) && done_.completed() && ^done_ == ^self &&
+                        forall<i: Int> 0 <= i && i < prod.len() ==> !filter::accepts(f, prod[i])
+                })]
+                fn any<F>(&mut self, f: F) -> bool
+                    where F: FnMut(Self::Item) -> bool;
+
+                #[requires(filter::immutable(f) && filter::no_precondition(f) && filter::precise(f))]
+                #[ensures(if result {
+                    exists<done_ : &mut Self_, prod: Seq<Self_::Item>> (*self).produces(prod, *done_) && done_.completed() && ^done_ == ^self &&
+                        forall<i: Int> 0 <= i && i < prod.len() ==> filter::accepts(f, prod[i])
+                } else {
+                    exists<prod: Seq<Self_::Item>, x: Self_::Item> (*self).produces(prod.push(x), ^self) && !filter::accepts(f, x) &&
+                        forall<i: Int> 0 <= i && i < prod.len() ==> filter::accepts(f, prod[i])
+                })]
+                fn all<F>(&mut self, f: F) -> bool
+                    where F: FnMut(Self::Item) -> bool;
+
+                #[requires(filter::immutable(predicate) && filter::no_precondition(predicate) && filter::precise(predicate))]
+                #[requires(forall<prod: Seq<Self_::Item>, i: Self_> (*self).produces(prod, i) ==> prod.len() <= @usize::MAX)]
+                #[ensures(match result {
+                    Some(n) => exists<prod: Seq<Self_::Item>, x: Self_::Item>
+                        @n == prod.len() && (*self).produces(prod.push(x), ^self) && filter::accepts(predicate, x) &&
+                        forall<i: Int> 0 <= i && i < prod.len() ==> !filter::accepts(predicate, prod[i]),
+                    None => exists<done_ : &mut Self_, prod: Seq<Self_::Item>> (*self).produces(prod, *done_) && done_.completed() && ^done_ == ^self &&
+                        forall<i: Int> 0 <= i && i < prod.len() ==> !filter::accepts(predicate, prod[i]),
+                })]
+                fn position<P>(&mut self, predicate: P) -> Option<usize>
+                    where P: FnMut(Self::Item) -> bool;
+
                 // TODO: Investigate why Self_ needed
                 #[ensures(exists<done_ : &mut Self_, prod: Seq<_>> (^done_).resolve() && done_.completed() &&
                     self.produces(prod, *done_) && B::from_iter_post(prod, result))]
@@ -114,6 +239,16 @@ extern_spec! {
                     where B: FromIterator<Self::Item>;
             }
 
+            trait DoubleEndedIterator
+                where Self: DoubleEndedIterator + Invariant {
+
+                #[ensures(match result {
+                    None => self.completed(),
+                    Some(v) => (*self).produces_back(Seq::singleton(v), ^self)
+                })]
+                fn next_back(&mut self) -> Option<Self::Item>;
+            }
+
             trait IntoIterator
                 where Self: IntoIterator {
 
@@ -135,6 +270,22 @@ extern_spec! {
                     where T: IntoIterator<Item = A>, T::IntoIter: Iterator;
             }
 
+            impl<I: Iterator> Peekable<I> {
+                #[ensures(match (*self).peeked() {
+                    Some(p) => (^self).peeked() == Some(p) && (*self).iter() == (^self).iter(),
+                    None => match (^self).peeked() {
+                        Some(Some(x)) => (*self).iter().produces(Seq::singleton(x), (^self).iter()),
+                        Some(None) => exists<e: &mut I> *e == (*self).iter() && ^e == (^self).iter() && e.completed(),
+                        None => false,
+                    },
+                })]
+                #[ensures(match result {
+                    Some(x) => (^self).peeked() == Some(Some(*x)),
+                    None => (^self).peeked() == Some(None),
+                })]
+                fn peek(&mut self) -> Option<&I::Item>;
+            }
+
             fn empty<T>() -> Empty<T>;
 
             #[ensures(@result == Some(value))]
@@ -0,0 +1,95 @@
+use crate::{invariant::Invariant, std::iter::Chain, *};
+
+pub trait ChainExt<A, B> {
+    #[logic]
+    fn a(self) -> Option<A>;
+
+    #[logic]
+    fn b(self) -> Option<B>;
+}
+
+impl<A, B> ChainExt<A, B> for Chain<A, B> {
+    #[logic]
+    #[trusted]
+    fn a(self) -> Option<A> {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[trusted]
+    fn b(self) -> Option<B> {
+        pearlite! { absurd }
+    }
+}
+
+#[trusted]
+impl<A, B> Resolve for Chain<A, B> {
+    #[predicate]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.a().resolve() && self.b().resolve()
+        }
+    }
+}
+
+impl<A: Invariant, B: Invariant> Invariant for Chain<A, B> {
+    #[predicate]
+    fn invariant(self) -> bool {
+        pearlite! {
+            (match self.a() { Some(a) => a.invariant(), None => true }) &&
+            match self.b() { Some(b) => b.invariant(), None => true }
+        }
+    }
+}
+
+#[predicate]
+fn produces_opt<I: Iterator>(i: Option<I>, visited: Seq<I::Item>, o: Option<I>) -> bool {
+    pearlite! {
+        match (i, o) {
+            (Some(i), Some(o)) => i.produces(visited, o),
+            (None, None) => visited == Seq::EMPTY,
+            _ => false,
+        }
+    }
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>> Iterator for Chain<A, B> {
+    #[predicate]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            // The first iterator is dropped once it is exhausted, the second one is kept
+            (^self).a() == None &&
+            (match (*self).a() { Some(a) => exists<e: &mut A> *e == a && e.completed(), None => true }) &&
+            match ((*self).b(), (^self).b()) {
+                (Some(b), Some(b2)) => exists<e: &mut B> *e == b && ^e == b2 && e.completed(),
+                (None, None) => true,
+                _ => false,
+            }
+        }
+    }
+
+    #[predicate]
+    fn produces(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            match (self.a(), o.a()) {
+                (Some(a), Some(a2)) => self.b() == o.b() && a.produces(visited, a2),
+                (Some(a), None) =>
+                    exists<v1: Seq<Self::Item>, v2: Seq<Self::Item>, e: &mut A>
+                        visited == v1.concat(v2) && a.produces(v1, *e) && e.completed() &&
+                        produces_opt(self.b(), v2, o.b()),
+                (None, None) => produces_opt(self.b(), visited, o.b()),
+                (None, Some(_)) => false,
+            }
+        }
+    }
+
+    #[law]
+    #[ensures(a.produces(Seq::EMPTY, a))]
+    fn produces_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
@@ -0,0 +1,113 @@
+use crate::{invariant::Invariant, std::iter::Filter, *};
+
+pub trait FilterExt<I, P> {
+    #[logic]
+    fn iter(self) -> I;
+
+    #[logic]
+    fn func(self) -> P;
+}
+
+impl<I, P> FilterExt<I, P> for Filter<I, P> {
+    #[logic]
+    #[trusted]
+    fn iter(self) -> I {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[trusted]
+    fn func(self) -> P {
+        pearlite! { absurd }
+    }
+}
+
+#[trusted]
+impl<I, P> Resolve for Filter<I, P> {
+    #[predicate]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.iter().resolve() && self.func().resolve()
+        }
+    }
+}
+
+impl<I: Invariant, P> Invariant for Filter<I, P> {
+    #[predicate]
+    fn invariant(self) -> bool {
+        self.iter().invariant()
+    }
+}
+
+/// Calling `f` never modifies its captured state
+#[predicate]
+pub fn immutable<A, F: FnMut(A) -> bool>(_: F) -> bool {
+    pearlite! {
+        forall<f: &mut F, a: A, b: bool> f.postcondition_mut((a,), b) ==> *f == ^f
+    }
+}
+
+/// `f` accepts any argument
+#[predicate]
+pub fn no_precondition<A, F: FnMut(A) -> bool>(_: F) -> bool {
+    pearlite! {
+        forall<f: F, a: A> f.precondition((a,))
+    }
+}
+
+/// `f` cannot return both `true` and `false` for the same argument
+#[predicate]
+pub fn precise<A, F: FnMut(A) -> bool>(_: F) -> bool {
+    pearlite! {
+        forall<f1: &mut F, f2: &mut F, a: A>
+            *f1 == *f2 ==> !(f1.postcondition_mut((a,), true) && f2.postcondition_mut((a,), false))
+    }
+}
+
+/// `f` returns `true` when called on `a`
+#[predicate]
+pub fn accepts<A, F: FnMut(A) -> bool>(f: F, a: A) -> bool {
+    pearlite! {
+        exists<g: &mut F> *g == f && ^g == f && g.postcondition_mut((a,), true)
+    }
+}
+
+impl<I: Iterator, P> Iterator for Filter<I, P>
+where
+    P: FnMut(&I::Item) -> bool,
+{
+    #[predicate]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            (*self).func() == (^self).func() &&
+            exists<s: Seq<Self::Item>, e: &mut I>
+                self.iter().produces(s, *e) && e.completed() && ^e == (^self).iter() &&
+                forall<i: Int, x: &Self::Item> 0 <= i && i < s.len() && *x == s[i] ==> !accepts(self.func(), x)
+        }
+    }
+
+    #[predicate]
+    fn produces(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            self.func() == o.func() &&
+            // `f` maps each produced element to its position in the items of the underlying iterator
+            exists<s: Seq<Self::Item>, f: logic::Mapping<Int, Int>>
+                self.iter().produces(s, o.iter()) &&
+                (forall<i: Int> 0 <= i && i < visited.len() ==> 0 <= f.get(i) && f.get(i) < s.len()) &&
+                (forall<i: Int, j: Int> 0 <= i && i < j && j < visited.len() ==> f.get(i) < f.get(j)) &&
+                (forall<i: Int> 0 <= i && i < visited.len() ==> visited[i] == s[f.get(i)]) &&
+                forall<i: Int, x: &Self::Item> 0 <= i && i < s.len() && *x == s[i] ==>
+                    (exists<j: Int> 0 <= j && j < visited.len() && f.get(j) == i) == accepts(self.func(), x)
+        }
+    }
+
+    #[law]
+    #[ensures(a.produces(Seq::EMPTY, a))]
+    fn produces_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
@@ -0,0 +1,17 @@
+use crate::*;
+
+/// Starting from the closure `f` and the accumulator `init`, folding over `prod` yields the
+/// accumulator `res` and leaves the closure in state `g`
+#[predicate]
+pub fn reaches<A, B, F: FnMut(B, A) -> B>(f: F, init: B, prod: Seq<A>, res: B, g: F) -> bool {
+    pearlite! {
+        exists<accs: Seq<B>, fs: Seq<&mut F>>
+            accs.len() == prod.len() + 1 && fs.len() == prod.len() &&
+            accs[0] == init && accs[prod.len()] == res &&
+            (if prod.len() == 0 { f == g } else { *fs[0] == f && ^fs[prod.len() - 1] == g }) &&
+            (forall<i: Int> 1 <= i && i < fs.len() ==> ^fs[i - 1] == *fs[i]) &&
+            forall<i: Int> 0 <= i && i < prod.len() ==>
+                (*fs[i]).precondition((accs[i], prod[i])) &&
+                fs[i].postcondition_mut((accs[i], prod[i]), accs[i + 1])
+    }
+}
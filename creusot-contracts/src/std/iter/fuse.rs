@@ -0,0 +1,88 @@
+use crate::{invariant::Invariant, std::iter::Fuse, *};
+
+pub trait FuseExt<I> {
+    /// The underlying iterator, or `None` once it has been exhausted
+    #[logic]
+    fn iter(self) -> Option<I>;
+}
+
+impl<I> FuseExt<I> for Fuse<I> {
+    #[logic]
+    #[trusted]
+    fn iter(self) -> Option<I> {
+        pearlite! { absurd }
+    }
+}
+
+#[trusted]
+impl<I> Resolve for Fuse<I> {
+    #[predicate]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.iter().resolve()
+        }
+    }
+}
+
+impl<I: Invariant> Invariant for Fuse<I> {
+    #[predicate]
+    fn invariant(self) -> bool {
+        pearlite! {
+            match self.iter() {
+                Some(i) => i.invariant(),
+                None => true,
+            }
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Fuse<I> {
+    #[predicate]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            (^self).iter() == None &&
+            match (*self).iter() {
+                Some(i) => exists<e: &mut I> *e == i && e.completed(),
+                None => true,
+            }
+        }
+    }
+
+    #[predicate]
+    fn produces(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            match (self.iter(), o.iter()) {
+                (Some(i), Some(i2)) => i.produces(visited, i2),
+                // An exhausted `Fuse` is left unchanged by `next`
+                (None, None) => visited == Seq::EMPTY && self == o,
+                _ => false,
+            }
+        }
+    }
+
+    #[law]
+    #[ensures(a.produces(Seq::EMPTY, a))]
+    fn produces_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+pub trait FusedIterator: ::std::iter::FusedIterator + Iterator {
+    #[law]
+    #[requires(self.completed())]
+    #[requires((^self).produces(steps, next))]
+    #[ensures(steps == Seq::EMPTY && ^self == next)]
+    fn is_fused(&mut self, steps: Seq<Self::Item>, next: Self);
+}
+
+impl<I: Iterator> FusedIterator for Fuse<I> {
+    #[law]
+    #[requires(self.completed())]
+    #[requires((^self).produces(steps, next))]
+    #[ensures(steps == Seq::EMPTY && ^self == next)]
+    fn is_fused(&mut self, steps: Seq<Self::Item>, next: Self) {}
+}
@@ -0,0 +1,117 @@
+use crate::{invariant::Invariant, std::iter::Map, *};
+
+pub trait MapExt<I, F> {
+    #[logic]
+    fn iter(self) -> I;
+
+    #[logic]
+    fn func(self) -> F;
+}
+
+impl<I, F> MapExt<I, F> for Map<I, F> {
+    #[logic]
+    #[trusted]
+    fn iter(self) -> I {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[trusted]
+    fn func(self) -> F {
+        pearlite! { absurd }
+    }
+}
+
+#[trusted]
+impl<I, F> Resolve for Map<I, F> {
+    #[predicate]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.iter().resolve() && self.func().resolve()
+        }
+    }
+}
+
+impl<I: Iterator, B, F: FnMut(I::Item) -> B> Invariant for Map<I, F> {
+    // Should not quantify over self or the `invariant` cannot be made into a type invariant
+    #[predicate]
+    #[creusot::ignore_type_invariant]
+    fn invariant(self) -> bool {
+        pearlite! {
+            reinitialize::<I, B, F>() &&
+            preservation(self.iter(), self.func()) &&
+            self.iter().invariant() &&
+            next_precondition(self.iter(), self.func())
+        }
+    }
+}
+
+impl<I: Iterator, B, F: FnMut(I::Item) -> B> Iterator for Map<I, F> {
+    #[predicate]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            (exists<inner : &mut _> *inner == self.iter() && ^inner == (^self).iter() && inner.completed()) &&
+            (*self).func() == (^self).func()
+        }
+    }
+
+    #[predicate]
+    #[why3::attr = "inline:trivial"]
+    fn produces(self, visited: Seq<Self::Item>, succ: Self) -> bool {
+        pearlite! {
+            self.func().unnest(succ.func())
+            && exists<s : Seq<I::Item>> s.len() == visited.len() && self.iter().produces(s, succ.iter())
+            && exists<fs: Seq<&mut F>> fs.len() == visited.len()
+            && (forall<i : Int> 1 <= i && i < fs.len() ==>  ^fs[i - 1] == * fs[i])
+            && if visited.len() == 0 { self.func() == succ.func() }
+               else { *fs[0] == self.func() &&  ^fs[visited.len() - 1] == succ.func() }
+            && forall<i : Int> 0 <= i && i < visited.len() ==>
+                 self.func().unnest(*fs[i])
+                 && (*fs[i]).precondition((s[i],))
+                 && fs[i].postcondition_mut((s[i],), visited[i])
+        }
+    }
+
+    #[law]
+    #[ensures(a.produces(Seq::EMPTY, a))]
+    fn produces_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+/// `func` can be called on the next element produced by `iter`
+#[predicate]
+pub fn next_precondition<I: Iterator, B, F: FnMut(I::Item) -> B>(iter: I, func: F) -> bool {
+    pearlite! {
+        forall<e: I::Item, i: I>
+            iter.produces(Seq::singleton(e), i) ==>
+            func.precondition((e,))
+    }
+}
+
+/// Calling `func` on an element produced by `iter` establishes the precondition for the next one
+#[predicate]
+pub fn preservation<I: Iterator, B, F: FnMut(I::Item) -> B>(iter: I, func: F) -> bool {
+    pearlite! {
+        forall<s: Seq<I::Item>, e1: I::Item, e2: I::Item, f: &mut F, b: B, i: I>
+            func.unnest(*f) ==>
+            iter.produces(s.push(e1).push(e2), i) ==>
+            (*f).precondition((e1,)) ==>
+            f.postcondition_mut((e1,), b) ==>
+            (^f).precondition((e2,))
+    }
+}
+
+/// The previous properties still hold once the underlying iterator has been exhausted
+#[predicate]
+pub fn reinitialize<I: Iterator, B, F: FnMut(I::Item) -> B>() -> bool {
+    pearlite! {
+        forall<iter: &mut I, func: F>
+            iter.completed() ==>
+            next_precondition(^iter, func) && preservation(^iter, func)
+    }
+}
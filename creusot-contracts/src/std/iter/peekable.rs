@@ -0,0 +1,79 @@
+use crate::{invariant::Invariant, std::iter::Peekable, *};
+
+pub trait PeekableExt<I: ::std::iter::Iterator> {
+    #[logic]
+    fn iter(self) -> I;
+
+    /// The element stored by the last call to `peek`, if it has not been consumed yet
+    #[logic]
+    fn peeked(self) -> Option<Option<I::Item>>;
+}
+
+impl<I: ::std::iter::Iterator> PeekableExt<I> for Peekable<I> {
+    #[logic]
+    #[trusted]
+    fn iter(self) -> I {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[trusted]
+    fn peeked(self) -> Option<Option<I::Item>> {
+        pearlite! { absurd }
+    }
+}
+
+#[trusted]
+impl<I: ::std::iter::Iterator> Resolve for Peekable<I> {
+    #[predicate]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.iter().resolve() && self.peeked().resolve()
+        }
+    }
+}
+
+impl<I: ::std::iter::Iterator + Invariant> Invariant for Peekable<I> {
+    #[predicate]
+    fn invariant(self) -> bool {
+        self.iter().invariant()
+    }
+}
+
+impl<I: Iterator> Iterator for Peekable<I> {
+    #[predicate]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            (^self).peeked() == None &&
+            match (*self).peeked() {
+                Some(None) => (*self).iter() == (^self).iter(),
+                Some(Some(_)) => false,
+                None => exists<e: &mut I> *e == (*self).iter() && ^e == (^self).iter() && e.completed(),
+            }
+        }
+    }
+
+    #[predicate]
+    fn produces(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            visited == Seq::EMPTY && self == o ||
+            o.peeked() == None &&
+            match self.peeked() {
+                None => self.iter().produces(visited, o.iter()),
+                Some(Some(x)) =>
+                    visited.len() > 0 && visited[0] == x && self.iter().produces(visited.tail(), o.iter()),
+                Some(None) => false,
+            }
+        }
+    }
+
+    #[law]
+    #[ensures(a.produces(Seq::EMPTY, a))]
+    fn produces_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
@@ -1,6 +1,6 @@
 use crate::{
     std::{
-        iter::Step,
+        iter::{DoubleEndedIterator, Step},
         ops::{Range, RangeInclusive},
     },
     *,
@@ -36,6 +36,29 @@ impl<Idx: DeepModel<DeepModelTy = Int> + Step> Iterator for Range<Idx> {
     fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
 }
 
+impl<Idx: DeepModel<DeepModelTy = Int> + Step> DoubleEndedIterator for Range<Idx> {
+    #[predicate]
+    fn produces_back(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            self.start == o.start && self.end.deep_model() >= o.end.deep_model()
+            && (visited.len() > 0 ==> o.end.deep_model() >= o.start.deep_model())
+            && visited.len() == self.end.deep_model() - o.end.deep_model()
+            && forall<i : Int> 0 <= i && i < visited.len() ==>
+                visited[i].deep_model() == self.end.deep_model() - (i + 1)
+        }
+    }
+
+    #[law]
+    #[ensures(a.produces_back(Seq::EMPTY, a))]
+    fn produces_back_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces_back(ab, b))]
+    #[requires(b.produces_back(bc, c))]
+    #[ensures(a.produces_back(ab.concat(bc), c))]
+    fn produces_back_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
 #[logic]
 #[ensures(r.is_empty_log() == (result == 0))]
 fn range_inclusive_len<Idx: DeepModel<DeepModelTy = Int>>(r: RangeInclusive<Idx>) -> Int {
@@ -0,0 +1,74 @@
+use crate::{
+    invariant::Invariant,
+    std::iter::{DoubleEndedIterator, Rev},
+    *,
+};
+
+pub trait RevExt<I> {
+    #[logic]
+    fn iter(self) -> I;
+}
+
+impl<I> RevExt<I> for Rev<I> {
+    #[logic]
+    #[trusted]
+    fn iter(self) -> I {
+        pearlite! { absurd }
+    }
+}
+
+#[trusted]
+impl<I> Resolve for Rev<I> {
+    #[predicate]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.iter().resolve()
+        }
+    }
+}
+
+impl<I: Invariant> Invariant for Rev<I> {
+    #[predicate]
+    fn invariant(self) -> bool {
+        self.iter().invariant()
+    }
+}
+
+impl<I: DoubleEndedIterator> Iterator for Rev<I> {
+    #[predicate]
+    fn completed(&mut self) -> bool {
+        pearlite! { exists<inner : &mut _> *inner == self.iter() && ^inner == (^self).iter() && inner.completed() }
+    }
+
+    #[predicate]
+    fn produces(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! { self.iter().produces_back(visited, o.iter()) }
+    }
+
+    #[law]
+    #[ensures(a.produces(Seq::EMPTY, a))]
+    fn produces_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for Rev<I> {
+    #[predicate]
+    fn produces_back(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! { self.iter().produces(visited, o.iter()) }
+    }
+
+    #[law]
+    #[ensures(a.produces_back(Seq::EMPTY, a))]
+    fn produces_back_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces_back(ab, b))]
+    #[requires(b.produces_back(bc, c))]
+    #[ensures(a.produces_back(ab.concat(bc), c))]
+    fn produces_back_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
@@ -0,0 +1,99 @@
+use crate::{invariant::Invariant, std::iter::StepBy, *};
+
+pub trait StepByExt<I> {
+    #[logic]
+    fn iter(self) -> I;
+
+    #[logic]
+    fn step(self) -> Int;
+
+    /// Whether the next element is taken directly, rather than after skipping `step - 1` elements
+    #[logic]
+    fn first_take(self) -> bool;
+}
+
+impl<I> StepByExt<I> for StepBy<I> {
+    #[logic]
+    #[trusted]
+    fn iter(self) -> I {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[trusted]
+    #[ensures(result > 0 && result <= @usize::MAX)]
+    fn step(self) -> Int {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[trusted]
+    fn first_take(self) -> bool {
+        pearlite! { absurd }
+    }
+}
+
+#[logic]
+fn skipped<I>(s: StepBy<I>) -> Int {
+    pearlite! { if s.first_take() { 0 } else { s.step() - 1 } }
+}
+
+#[trusted]
+impl<I> Resolve for StepBy<I> {
+    #[predicate]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.iter().resolve()
+        }
+    }
+}
+
+impl<I: Invariant> Invariant for StepBy<I> {
+    #[predicate]
+    fn invariant(self) -> bool {
+        self.iter().invariant()
+    }
+}
+
+impl<I: Iterator> Iterator for StepBy<I> {
+    #[predicate]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            (^self).step() == (*self).step() && !(^self).first_take() &&
+            exists<s: Seq<Self::Item>, e: &mut I>
+                s.len() <= skipped(*self) &&
+                self.iter().produces(s, *e) &&
+                (forall<i: Int> 0 <= i && i < s.len() ==> s[i].resolve()) &&
+                e.completed() &&
+                ^e == (^self).iter()
+        }
+    }
+
+    #[predicate]
+    fn produces(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            visited == Seq::EMPTY && self == o ||
+            self.step() == o.step() && !o.first_take() && visited.len() > 0 &&
+            exists<s: Seq<Self::Item>>
+                s.len() == skipped(self) + 1 + (visited.len() - 1) * self.step() &&
+                self.iter().produces(s, o.iter()) &&
+                // The `i`th element is taken after skipping the first ones and `step - 1` more
+                // elements after each element taken before it
+                (forall<i: Int> 0 <= i && i < visited.len() ==>
+                    s[skipped(self) + i * self.step()] == visited[i]) &&
+                (forall<i: Int> 0 <= i && i < skipped(self) ==> s[i].resolve()) &&
+                forall<i: Int, j: Int> 0 <= i && i < visited.len() - 1 && 0 < j && j < self.step() ==>
+                    s[skipped(self) + i * self.step() + j].resolve()
+        }
+    }
+
+    #[law]
+    #[ensures(a.produces(Seq::EMPTY, a))]
+    fn produces_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
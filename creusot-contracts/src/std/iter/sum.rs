@@ -0,0 +1,53 @@
+use crate::*;
+
+pub trait Sum<A>: ::std::iter::Sum<A> {
+    #[predicate]
+    fn sum_pre(_prod: Seq<A>) -> bool {
+        pearlite! { true }
+    }
+
+    #[predicate]
+    fn sum_post(prod: Seq<A>, res: Self) -> bool;
+}
+
+#[logic]
+#[variant(s.len())]
+fn seq_sum<T: DeepModel<DeepModelTy = Int>>(s: Seq<T>) -> Int {
+    pearlite! {
+        if s.len() == 0 { 0 }
+        else { seq_sum(s.subsequence(0, s.len() - 1)) + s[s.len() - 1].deep_model() }
+    }
+}
+
+macro_rules! spec_sum {
+    ($($t:ty),*) => {
+        $(
+            impl Sum<$t> for $t {
+                // Summing panics on overflow
+                #[predicate]
+                fn sum_pre(prod: Seq<$t>) -> bool {
+                    pearlite! { @$t::MIN <= seq_sum(prod) && seq_sum(prod) <= @$t::MAX }
+                }
+
+                #[predicate]
+                fn sum_post(prod: Seq<$t>, res: Self) -> bool {
+                    pearlite! { @res == seq_sum(prod) }
+                }
+            }
+
+            impl<'a> Sum<&'a $t> for $t {
+                #[predicate]
+                fn sum_pre(prod: Seq<&'a $t>) -> bool {
+                    pearlite! { @$t::MIN <= seq_sum(prod) && seq_sum(prod) <= @$t::MAX }
+                }
+
+                #[predicate]
+                fn sum_post(prod: Seq<&'a $t>, res: Self) -> bool {
+                    pearlite! { @res == seq_sum(prod) }
+                }
+            }
+        )*
+    };
+}
+
+spec_sum!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,74 @@
+use crate::{invariant::Invariant, std::iter::Zip, *};
+
+pub trait ZipExt<A, B> {
+    #[logic]
+    fn itera(self) -> A;
+
+    #[logic]
+    fn iterb(self) -> B;
+}
+
+impl<A, B> ZipExt<A, B> for Zip<A, B> {
+    #[logic]
+    #[trusted]
+    fn itera(self) -> A {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[trusted]
+    fn iterb(self) -> B {
+        pearlite! { absurd }
+    }
+}
+
+#[trusted]
+impl<A, B> Resolve for Zip<A, B> {
+    #[predicate]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.itera().resolve() && self.iterb().resolve()
+        }
+    }
+}
+
+impl<A: Invariant, B: Invariant> Invariant for Zip<A, B> {
+    #[predicate]
+    fn invariant(self) -> bool {
+        self.itera().invariant() && self.iterb().invariant()
+    }
+}
+
+impl<A: Iterator, B: Iterator> Iterator for Zip<A, B> {
+    #[predicate]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            // `a` is polled first, `b` is only polled if `a` produced an element
+            (exists<a: &mut A> *a == (*self).itera() && ^a == (^self).itera() && a.completed()
+                && (*self).iterb() == (^self).iterb())
+            || exists<x: A::Item, b: &mut B>
+                (*self).itera().produces(Seq::singleton(x), (^self).itera()) && x.resolve()
+                && *b == (*self).iterb() && ^b == (^self).iterb() && b.completed()
+        }
+    }
+
+    #[predicate]
+    fn produces(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            exists<p1: Seq<A::Item>, p2: Seq<B::Item>>
+                p1.len() == p2.len() && p2.len() == visited.len() &&
+                (forall<i: Int> 0 <= i && i < visited.len() ==> visited[i] == (p1[i], p2[i])) &&
+                self.itera().produces(p1, o.itera()) && self.iterb().produces(p2, o.iterb())
+        }
+    }
+
+    #[law]
+    #[ensures(a.produces(Seq::EMPTY, a))]
+    fn produces_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
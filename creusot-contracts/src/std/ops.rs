@@ -203,6 +203,93 @@ impl<Idx> RangeInclusiveExt<Idx> for RangeInclusive<Idx> {
     }
 }
 
+/// Logical bounds of ranges of indices, used by functions taking a `RangeBounds<usize>`
+pub trait RangeBoundsExt: RangeBounds<usize> {
+    /// The first index in the range
+    #[logic]
+    fn lower_bound(self) -> Int;
+
+    /// The index following the range, for a sequence of length `len`
+    #[logic]
+    fn upper_bound(self, len: Int) -> Int;
+}
+
+impl RangeBoundsExt for Range<usize> {
+    #[logic]
+    fn lower_bound(self) -> Int {
+        pearlite! { @self.start }
+    }
+
+    #[logic]
+    fn upper_bound(self, _len: Int) -> Int {
+        pearlite! { @self.end }
+    }
+}
+
+impl RangeBoundsExt for RangeFrom<usize> {
+    #[logic]
+    fn lower_bound(self) -> Int {
+        pearlite! { @self.start }
+    }
+
+    #[logic]
+    fn upper_bound(self, len: Int) -> Int {
+        len
+    }
+}
+
+impl RangeBoundsExt for RangeTo<usize> {
+    #[logic]
+    fn lower_bound(self) -> Int {
+        pearlite! { 0 }
+    }
+
+    #[logic]
+    fn upper_bound(self, _len: Int) -> Int {
+        pearlite! { @self.end }
+    }
+}
+
+impl RangeBoundsExt for RangeToInclusive<usize> {
+    #[logic]
+    fn lower_bound(self) -> Int {
+        pearlite! { 0 }
+    }
+
+    #[logic]
+    fn upper_bound(self, _len: Int) -> Int {
+        pearlite! { @self.end + 1 }
+    }
+}
+
+impl RangeBoundsExt for RangeInclusive<usize> {
+    #[logic]
+    fn lower_bound(self) -> Int {
+        pearlite! { @self.start_log() }
+    }
+
+    // An empty range is either exhausted or panics when used as bounds, which cannot be told
+    // apart: its upper bound is placed before its lower bound so that it cannot be used
+    #[logic]
+    fn upper_bound(self, _len: Int) -> Int {
+        pearlite! {
+            if self.is_empty_log() { @self.start_log() - 1 } else { @self.end_log() + 1 }
+        }
+    }
+}
+
+impl RangeBoundsExt for RangeFull {
+    #[logic]
+    fn lower_bound(self) -> Int {
+        pearlite! { 0 }
+    }
+
+    #[logic]
+    fn upper_bound(self, len: Int) -> Int {
+        len
+    }
+}
+
 extern_spec! {
     mod std {
         mod ops {
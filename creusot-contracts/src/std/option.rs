@@ -1,5 +1,9 @@
 use crate::{invariant::Invariant, *};
 pub use ::std::option::*;
+use ::std::{
+    convert::Infallible,
+    ops::{ControlFlow, FromResidual, Try},
+};
 
 extern_spec! {
     mod std {
@@ -259,3 +263,19 @@ impl<'a, T> IntoIterator for &'a mut Option<T> {
         }
     }
 }
+
+extern_spec! {
+    impl<T> Try for Option<T> {
+        #[ensures(forall<t: T> self == Some(t) ==> result == ControlFlow::Continue(t))]
+        #[ensures(self == None ==> result == ControlFlow::Break(None))]
+        fn branch(self) -> ControlFlow<Option<Infallible>, T>;
+
+        #[ensures(result == Some(output))]
+        fn from_output(output: T) -> Self;
+    }
+
+    impl<T> FromResidual<Option<Infallible>> for Option<T> {
+        #[ensures(result == None)]
+        fn from_residual(residual: Option<Infallible>) -> Self;
+    }
+}
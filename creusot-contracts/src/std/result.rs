@@ -1,6 +1,10 @@
 use crate::*;
 
-use ::std::fmt::Debug;
+use ::std::{
+    convert::Infallible,
+    fmt::Debug,
+    ops::{ControlFlow, Try},
+};
 
 extern_spec! {
     mod std {
@@ -104,3 +108,14 @@ extern_spec! {
         }
     }
 }
+
+extern_spec! {
+    impl<T, E> Try for Result<T, E> {
+        #[ensures(forall<t: T> self == Ok(t) ==> result == ControlFlow::Continue(t))]
+        #[ensures(forall<e: E> self == Err(e) ==> result == ControlFlow::Break(Err(e)))]
+        fn branch(self) -> ControlFlow<Result<Infallible, E>, T>;
+
+        #[ensures(result == Ok(output))]
+        fn from_output(output: T) -> Self;
+    }
+}
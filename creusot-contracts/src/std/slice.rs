@@ -2,6 +2,8 @@ use crate::{
     invariant::Invariant,
     std::{
         alloc::Allocator,
+        cmp::Ordering,
+        iter::DoubleEndedIterator,
         ops::{Index, IndexMut, Range, RangeFrom, RangeFull, RangeTo, RangeToInclusive},
     },
     *,
@@ -278,6 +280,52 @@ extern_spec! {
         fn binary_search(&self, x : &T) -> Result<usize, usize>
             where T: Ord + DeepModel,  T::DeepModelTy: OrdLogic,;
 
+        // Without constraints on the comparison function, only the bounds of the result are known
+        #[ensures(match result {
+            Ok(i) => @i < (@self).len(),
+            Err(i) => @i <= (@self).len(),
+        })]
+        fn binary_search_by<'a, F>(&'a self, f: F) -> Result<usize, usize>
+            where F: FnMut(&'a T) -> Ordering;
+
+        #[ensures(result == self.deep_model().contains(x.deep_model()))]
+        fn contains(&self, x: &T) -> bool
+            where T: PartialEq + DeepModel;
+
+        #[ensures((^self).deep_model().sorted())]
+        #[ensures((@^self).permutation_of(@self))]
+        fn sort(&mut self)
+            where T: Ord + DeepModel, T::DeepModelTy: OrdLogic;
+
+        #[ensures((^self).deep_model().sorted())]
+        #[ensures((@^self).permutation_of(@self))]
+        fn sort_unstable(&mut self)
+            where T: Ord + DeepModel, T::DeepModelTy: OrdLogic;
+
+        // Without constraints on the key function, the result is only known to be a permutation
+        #[ensures((@^self).permutation_of(@self))]
+        fn sort_by_key<K, F>(&mut self, f: F)
+            where F: FnMut(&T) -> K, K: Ord;
+
+        #[ensures((@^self).len() == (@self).len())]
+        #[ensures(forall<i : Int> 0 <= i && i < (@self).len() ==>
+            (@^self)[i] == (@self)[(@self).len() - 1 - i])]
+        fn reverse(&mut self);
+
+        #[requires(@mid <= (@self).len())]
+        #[ensures((@^self).ext_eq((@self).subsequence(@mid, (@self).len()).concat((@self).subsequence(0, @mid))))]
+        fn rotate_left(&mut self, mid: usize);
+
+        #[requires((@self).len() == (@src).len())]
+        #[ensures(@^self == @src)]
+        fn copy_from_slice(&mut self, src: &[T])
+            where T: Copy;
+
+        #[ensures((@^self).len() == (@self).len())]
+        #[ensures(forall<i : Int> 0 <= i && i < (@self).len() ==> (@^self)[i] == value)]
+        fn fill(&mut self, value: T)
+            where T: Clone;
+
         #[ensures(@result == @self_)]
         fn into_vec<A: Allocator>(self_: Box<Self, A>) -> Vec<T, A>;
     }
@@ -360,6 +408,28 @@ impl<'a, T> Iterator for Iter<'a, T> {
     fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
 }
 
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    #[predicate]
+    fn produces_back(self, visited: Seq<Self::Item>, tl: Self) -> bool {
+        pearlite! {
+            (@self).to_ref_seq().len() == visited.len() + (@tl).to_ref_seq().len()
+            && (@tl).to_ref_seq() == (@self).to_ref_seq().subsequence(0, (@tl).to_ref_seq().len())
+            && forall<i: Int> 0 <= i && i < visited.len() ==>
+                visited[i] == (@self).to_ref_seq()[(@self).to_ref_seq().len() - 1 - i]
+        }
+    }
+
+    #[law]
+    #[ensures(a.produces_back(Seq::EMPTY, a))]
+    fn produces_back_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces_back(ab, b))]
+    #[requires(b.produces_back(bc, c))]
+    #[ensures(a.produces_back(ab.concat(bc), c))]
+    fn produces_back_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
 impl<'a, T> ShallowModel for IterMut<'a, T> {
     type ShallowModelTy = &'a mut [T];
 
@@ -411,3 +481,25 @@ impl<'a, T> Iterator for IterMut<'a, T> {
     #[ensures(a.produces(ab.concat(bc), c))]
     fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
 }
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    #[predicate]
+    fn produces_back(self, visited: Seq<Self::Item>, tl: Self) -> bool {
+        pearlite! {
+            (@self).to_mut_seq().len() == visited.len() + (@tl).to_mut_seq().len()
+            && (@tl).to_mut_seq() == (@self).to_mut_seq().subsequence(0, (@tl).to_mut_seq().len())
+            && forall<i: Int> 0 <= i && i < visited.len() ==>
+                visited[i] == (@self).to_mut_seq()[(@self).to_mut_seq().len() - 1 - i]
+        }
+    }
+
+    #[law]
+    #[ensures(a.produces_back(Seq::EMPTY, a))]
+    fn produces_back_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces_back(ab, b))]
+    #[requires(b.produces_back(bc, c))]
+    #[ensures(a.produces_back(ab.concat(bc), c))]
+    fn produces_back_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
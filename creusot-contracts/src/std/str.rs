@@ -0,0 +1,78 @@
+use crate::{invariant::Invariant, std::iter::DoubleEndedIterator, *};
+pub use ::std::str::*;
+
+impl ShallowModel for str {
+    type ShallowModelTy = Seq<char>;
+
+    #[logic]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        pearlite! { absurd }
+    }
+}
+
+extern_spec! {
+    impl str {
+        #[ensures(result == ((@self).len() == 0))]
+        fn is_empty(&self) -> bool;
+
+        #[ensures(@result == @self)]
+        fn chars(&self) -> Chars<'_>;
+    }
+}
+
+impl<'a> ShallowModel for Chars<'a> {
+    type ShallowModelTy = Seq<char>;
+
+    #[logic]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        pearlite! { absurd }
+    }
+}
+
+impl<'a> Invariant for Chars<'a> {}
+
+impl<'a> Iterator for Chars<'a> {
+    #[predicate]
+    fn completed(&mut self) -> bool {
+        pearlite! { self.resolve() && @*self == Seq::EMPTY }
+    }
+
+    #[predicate]
+    fn produces(self, visited: Seq<Self::Item>, tl: Self) -> bool {
+        pearlite! { @self == visited.concat(@tl) }
+    }
+
+    #[law]
+    #[ensures(a.produces(Seq::EMPTY, a))]
+    fn produces_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+impl<'a> DoubleEndedIterator for Chars<'a> {
+    #[predicate]
+    fn produces_back(self, visited: Seq<Self::Item>, tl: Self) -> bool {
+        pearlite! {
+            (@self).len() == visited.len() + (@tl).len()
+            && @tl == (@self).subsequence(0, (@tl).len())
+            && forall<i: Int> 0 <= i && i < visited.len() ==>
+                visited[i] == (@self)[(@self).len() - 1 - i]
+        }
+    }
+
+    #[law]
+    #[ensures(a.produces_back(Seq::EMPTY, a))]
+    fn produces_back_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces_back(ab, b))]
+    #[requires(b.produces_back(bc, c))]
+    #[ensures(a.produces_back(ab.concat(bc), c))]
+    fn produces_back_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
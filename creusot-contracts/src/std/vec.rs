@@ -2,7 +2,8 @@ use crate::{
     invariant::Invariant,
     std::{
         alloc::Allocator,
-        ops::{Deref, DerefMut, Index, IndexMut},
+        iter::DoubleEndedIterator,
+        ops::{Deref, DerefMut, Index, IndexMut, RangeBoundsExt},
         slice::SliceIndex,
     },
     Default, *,
@@ -102,6 +103,17 @@ extern_spec! {
 
                 #[ensures((@^self).len() == 0)]
                 fn clear(&mut self);
+
+                #[requires(range.lower_bound() <= range.upper_bound((@self).len()))]
+                #[requires(range.upper_bound((@self).len()) <= (@self).len())]
+                #[ensures(@result == (@self).subsequence(range.lower_bound(), range.upper_bound((@self).len())))]
+                // The vector is only restored when the `Drain` is dropped: if it is leaked instead,
+                // the vector is truncated before the range
+                #[ensures(@^self == (@self).subsequence(0, range.lower_bound())
+                    .concat((@self).subsequence(range.upper_bound((@self).len()), (@self).len()))
+                    || (@^self).len() <= range.lower_bound())]
+                fn drain<R>(&mut self, range: R) -> Drain<'_, T, A>
+                    where R: RangeBoundsExt;
             }
 
             impl<T, A : Allocator> Extend<T> for Vec<T, A> {
@@ -227,9 +239,97 @@ impl<T, A: Allocator> Iterator for std::vec::IntoIter<T, A> {
     fn produces_trans(a: Self, ab: Seq<T>, b: Self, bc: Seq<T>, c: Self) {}
 }
 
+impl<T, A: Allocator> DoubleEndedIterator for std::vec::IntoIter<T, A> {
+    #[predicate]
+    fn produces_back(self, visited: Seq<T>, tl: Self) -> bool {
+        pearlite! {
+            (@self).len() == visited.len() + (@tl).len()
+            && @tl == (@self).subsequence(0, (@tl).len())
+            && forall<i: Int> 0 <= i && i < visited.len() ==>
+                visited[i] == (@self)[(@self).len() - 1 - i]
+        }
+    }
+
+    #[law]
+    #[ensures(a.produces_back(Seq::EMPTY, a))]
+    fn produces_back_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces_back(ab, b))]
+    #[requires(b.produces_back(bc, c))]
+    #[ensures(a.produces_back(ab.concat(bc), c))]
+    fn produces_back_trans(a: Self, ab: Seq<T>, b: Self, bc: Seq<T>, c: Self) {}
+}
+
 impl<T> FromIterator<T> for Vec<T> {
     #[predicate]
     fn from_iter_post(prod: Seq<T>, res: Self) -> bool {
         pearlite! { prod == @res }
     }
 }
+
+impl<'a, T, A: Allocator> ShallowModel for Drain<'a, T, A> {
+    type ShallowModelTy = Seq<T>;
+
+    #[logic]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        absurd
+    }
+}
+
+#[trusted]
+impl<'a, T, A: Allocator> Resolve for Drain<'a, T, A> {
+    #[predicate]
+    fn resolve(self) -> bool {
+        pearlite! { forall<i: Int> 0 <= i && i < (@self).len() ==> (@self)[i].resolve() }
+    }
+}
+
+impl<'a, T, A: Allocator> Invariant for Drain<'a, T, A> {}
+
+impl<'a, T, A: Allocator> Iterator for Drain<'a, T, A> {
+    #[predicate]
+    fn completed(&mut self) -> bool {
+        pearlite! { self.resolve() && @self == Seq::EMPTY }
+    }
+
+    #[predicate]
+    fn produces(self, visited: Seq<T>, rhs: Self) -> bool {
+        pearlite! {
+            @self == visited.concat(@rhs)
+        }
+    }
+
+    #[law]
+    #[ensures(a.produces(Seq::EMPTY, a))]
+    fn produces_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<T>, b: Self, bc: Seq<T>, c: Self) {}
+}
+
+impl<'a, T, A: Allocator> DoubleEndedIterator for Drain<'a, T, A> {
+    #[predicate]
+    fn produces_back(self, visited: Seq<T>, tl: Self) -> bool {
+        pearlite! {
+            (@self).len() == visited.len() + (@tl).len()
+            && @tl == (@self).subsequence(0, (@tl).len())
+            && forall<i: Int> 0 <= i && i < visited.len() ==>
+                visited[i] == (@self)[(@self).len() - 1 - i]
+        }
+    }
+
+    #[law]
+    #[ensures(a.produces_back(Seq::EMPTY, a))]
+    fn produces_back_refl(a: Self) {}
+
+    #[law]
+    #[requires(a.produces_back(ab, b))]
+    #[requires(b.produces_back(bc, c))]
+    #[ensures(a.produces_back(ab.concat(bc), c))]
+    fn produces_back_trans(a: Self, ab: Seq<T>, b: Self, bc: Seq<T>, c: Self) {}
+}
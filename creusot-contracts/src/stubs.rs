@@ -1,3 +1,5 @@
+use std::marker::Tuple;
+
 #[creusot::no_translate]
 #[rustc_diagnostic_item = "fin"]
 pub fn fin<T: ?Sized>(_: &mut T) -> Box<T> {
@@ -24,13 +26,19 @@ pub fn neq<T: ?Sized>(_: T, _: T) -> bool {
 
 #[creusot::no_translate]
 #[rustc_diagnostic_item = "exists"]
-pub fn exists<T, F: Fn(T) -> bool>(_: F) -> bool {
+pub fn exists<T: Tuple, F: Fn<T, Output = bool>>(_: F) -> bool {
     panic!()
 }
 
 #[creusot::no_translate]
 #[rustc_diagnostic_item = "forall"]
-pub fn forall<T, F: Fn(T) -> bool>(_: F) -> bool {
+pub fn forall<T: Tuple, F: Fn<T, Output = bool>>(_: F) -> bool {
+    panic!()
+}
+
+#[creusot::no_translate]
+#[rustc_diagnostic_item = "trigger"]
+pub fn trigger<T>(_: T, _: bool) -> bool {
     panic!()
 }
 
@@ -46,6 +54,18 @@ pub fn old<T>(_: T) -> T {
     panic!()
 }
 
+#[creusot::no_translate]
+#[rustc_diagnostic_item = "at"]
+pub fn at<T>(_: &'static str, _: T) -> T {
+    panic!()
+}
+
+#[creusot::no_translate]
+#[rustc_diagnostic_item = "at_entry"]
+pub fn at_entry<T>(_: T) -> T {
+    panic!()
+}
+
 #[creusot::no_translate]
 #[rustc_diagnostic_item = "absurd"]
 pub fn abs<T>() -> T {
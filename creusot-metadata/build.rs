@@ -0,0 +1,28 @@
+use std::{path::Path, process::Command};
+
+// Records the commit Creusot is built from, so that metadata produced by other builds can be told apart
+fn main() {
+    let hash = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .map(|out| String::from_utf8_lossy(&out.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=CREUSOT_GIT_HASH={hash}");
+
+    // Rebuild when a commit is made or another branch is checked out
+    let git_dir = Path::new("../.git");
+    rerun_if_exists(&git_dir.join("HEAD"));
+    if let Ok(head) = std::fs::read_to_string(git_dir.join("HEAD")) {
+        if let Some(branch) = head.trim().strip_prefix("ref: ") {
+            rerun_if_exists(&git_dir.join(branch));
+        }
+    }
+}
+
+fn rerun_if_exists(path: &Path) {
+    if path.exists() {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::header::MetadataHeader;
 use rustc_data_structures::{
     fx::FxHashMap,
     owning_ref::OwningRef,
@@ -11,7 +12,9 @@ use rustc_middle::{
 };
 use rustc_serialize::opaque;
 pub use rustc_serialize::{Decodable, Decoder};
-use std::{fs::File, io::Read, path::Path};
+use std::{convert::TryInto, fs::File, io::Read, path::Path};
+
+const ROOT_POSITION_SIZE: usize = std::mem::size_of::<u64>();
 
 // copied from rustc
 #[derive(Clone)]
@@ -23,10 +26,29 @@ impl MetadataBlob {
         let mut file = File::open(path)?;
         file.read_to_end(&mut encoded)?;
 
+        if encoded.len() < ROOT_POSITION_SIZE {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "metadata is missing its root position",
+            ));
+        }
+
         let encoded_owned = OwningRef::new(encoded);
         let metadat_ref: OwningRef<Box<_>, [u8]> = encoded_owned.map_owner_box();
         Ok(MetadataBlob(Lrc::new(rustc_erase_owner!(metadat_ref))))
     }
+
+    /// The header identifying the build of Creusot which produced the metadata, or `None` if the
+    /// metadata predates headers
+    pub fn header(&self) -> Option<MetadataHeader> {
+        MetadataHeader::decode(&mut opaque::MemDecoder::new(&self.0, 0))
+    }
+
+    /// The position of the root of the metadata, written by `MetadataEncoder::finish`
+    pub fn root_position(&self) -> usize {
+        let (_, root) = self.0.split_at(self.0.len() - ROOT_POSITION_SIZE);
+        u64::from_le_bytes(root.try_into().unwrap()) as usize
+    }
 }
 
 // This is only safe to decode the metadata of a single crate or the `ty_rcache` might confuse shorthands (see #360)
@@ -9,6 +9,8 @@ use rustc_data_structures::fx::{FxHashMap, FxIndexSet};
 use rustc_hir::def_id::{CrateNum, DefId, DefIndex};
 use rustc_middle::ty::TyCtxt;
 
+use crate::header::MetadataHeader;
+
 pub struct MetadataEncoder<'tcx> {
     tcx: TyCtxt<'tcx>,
     opaque: opaque::MemEncoder,
@@ -19,17 +21,24 @@ pub struct MetadataEncoder<'tcx> {
 
 impl<'tcx> MetadataEncoder<'tcx> {
     pub fn new(tcx: TyCtxt<'tcx>) -> Self {
+        let mut opaque = opaque::MemEncoder::new();
+        MetadataHeader::current().encode(&mut opaque);
+
         MetadataEncoder {
             tcx,
-            opaque: opaque::MemEncoder::new(),
+            opaque,
             type_shorthands: Default::default(),
             predicate_shorthands: Default::default(),
             interpret_allocs: Default::default(),
         }
     }
 
-    pub fn finish(self) -> Vec<u8> {
-        self.opaque.finish()
+    /// Returns the encoded metadata, followed by the position of its `root`: the entry point
+    /// from which the rest of the metadata can be decoded on demand.
+    pub fn finish(self, root: usize) -> Vec<u8> {
+        let mut data = self.opaque.finish();
+        data.extend_from_slice(&(root as u64).to_le_bytes());
+        data
     }
 }
 
@@ -0,0 +1,56 @@
+use rustc_serialize::{
+    opaque::{MemDecoder, MemEncoder},
+    Decoder, Encoder,
+};
+
+// Starts every metadata file, so that files from before the introduction of the header are detected
+const MAGIC: &[u8] = b"CREUSOT-METADATA";
+
+/// Version of the layout of metadata files, which must be bumped whenever it changes.
+pub const FORMAT_VERSION: u32 = 4;
+
+/// Identifies the build of Creusot which produced a metadata file. Metadata can only be read by
+/// the exact same build, as it relies on the internal encodings of both Creusot and rustc.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MetadataHeader {
+    pub format_version: u32,
+    pub creusot_version: String,
+    pub rustc_version: String,
+}
+
+impl MetadataHeader {
+    pub fn current() -> Self {
+        MetadataHeader {
+            format_version: FORMAT_VERSION,
+            creusot_version: format!(
+                "{} ({})",
+                env!("CARGO_PKG_VERSION"),
+                env!("CREUSOT_GIT_HASH")
+            ),
+            rustc_version: rustc_interface::util::rustc_version_str()
+                .unwrap_or("unknown")
+                .to_string(),
+        }
+    }
+
+    // The layout of the header must stay the same across format versions
+    pub(crate) fn encode(&self, e: &mut MemEncoder) {
+        e.emit_raw_bytes(MAGIC);
+        e.emit_u32(self.format_version);
+        e.emit_str(&self.creusot_version);
+        e.emit_str(&self.rustc_version);
+    }
+
+    // Returns `None` when the data does not start with a header
+    pub(crate) fn decode(d: &mut MemDecoder) -> Option<Self> {
+        if d.data.len() - d.position() < MAGIC.len() || d.read_raw_bytes(MAGIC.len()) != MAGIC {
+            return None;
+        }
+
+        Some(MetadataHeader {
+            format_version: d.read_u32(),
+            creusot_version: d.read_str().to_string(),
+            rustc_version: d.read_str().to_string(),
+        })
+    }
+}
@@ -4,6 +4,7 @@
 extern crate rustc_data_structures;
 extern crate rustc_driver;
 extern crate rustc_hir;
+extern crate rustc_interface;
 extern crate rustc_metadata;
 extern crate rustc_middle;
 extern crate rustc_serialize;
@@ -12,3 +13,4 @@ extern crate rustc_span;
 
 pub mod decoder;
 pub mod encoder;
+pub mod header;
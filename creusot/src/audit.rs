@@ -0,0 +1,181 @@
+// Lists the items which verified code relies on without them being proved: the trust base.
+
+use crate::{
+    ctx::TranslationCtx,
+    termination,
+    translation::pearlite::{super_visit_term, Term, TermKind, TermVisitor},
+    util,
+};
+use rustc_hir::def_id::{DefId, LOCAL_CRATE};
+use rustc_span::Symbol;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    fmt,
+};
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum Assumption {
+    // Mapped directly to a Why3 symbol through `creusot::builtins`
+    Builtin(Symbol),
+    Trusted,
+    // External function whose contract is given by an `extern_spec!`
+    ExternSpec,
+    // Law of a trait, assumed whenever a generic implementation is used
+    Law,
+    // External function called by a terminating function, see `termination::assumed_terminating`
+    Terminates,
+}
+
+impl fmt::Display for Assumption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Assumption::Builtin(sym) => write!(f, "builtin ({sym})"),
+            Assumption::Trusted => write!(f, "trusted"),
+            Assumption::ExternSpec => write!(f, "extern spec"),
+            Assumption::Law => write!(f, "law"),
+            Assumption::Terminates => write!(f, "assumed to terminate"),
+        }
+    }
+}
+
+pub(crate) struct TrustBase {
+    crate_name: String,
+    focus: Option<String>,
+    // Assumed items, grouped by the crate defining them
+    crates: BTreeMap<String, BTreeSet<(String, Assumption)>>,
+}
+
+/// Computes the trust base of the local items matching `--focus-on`, or of the whole crate.
+pub(crate) fn trust_base(ctx: &mut TranslationCtx) -> TrustBase {
+    let tcx = ctx.tcx;
+    let focus = ctx.opts.match_str.clone();
+
+    let mut todo: Vec<DefId> = tcx
+        .hir()
+        .body_owners()
+        .map(|id| id.to_def_id())
+        .filter(|id| ctx.dependencies(*id).is_some())
+        .filter(|id| focus.as_ref().map_or(true, |f| tcx.def_path_str(*id).contains(&f[..])))
+        .collect();
+    let mut seen: HashSet<DefId> = todo.iter().cloned().collect();
+
+    let mut crates: BTreeMap<_, BTreeSet<_>> = BTreeMap::new();
+    while let Some(def_id) = todo.pop() {
+        let assumption = assumption(ctx, def_id);
+        // The contract of an extern spec is not part of the dependencies of the external
+        // function, but the logic items it uses are assumed as well
+        if assumption == Some(Assumption::ExternSpec) {
+            for dep in ctx.sig(def_id).contract.terms().flat_map(items) {
+                if seen.insert(dep) {
+                    todo.push(dep);
+                }
+            }
+        }
+
+        if let Some(assumption) = assumption {
+            crates
+                .entry(tcx.crate_name(def_id.krate).to_string())
+                .or_default()
+                .insert((tcx.def_path_str(def_id), assumption));
+        }
+
+        for callee in assumed_terminating_callees(ctx, def_id) {
+            crates
+                .entry(tcx.crate_name(callee.krate).to_string())
+                .or_default()
+                .insert((tcx.def_path_str(callee), Assumption::Terminates));
+        }
+
+        let deps = if def_id.is_local() {
+            ctx.dependencies(def_id)
+        } else {
+            ctx.externs.dependencies(def_id)
+        };
+        let body_deps = ctx.body_dependencies(def_id);
+        for ((dep, _), _) in deps.into_iter().chain(body_deps).flatten() {
+            if seen.insert(*dep) {
+                todo.push(*dep);
+            }
+        }
+    }
+
+    TrustBase { crate_name: tcx.crate_name(LOCAL_CRATE).to_string(), focus, crates }
+}
+
+// The external functions called by `def_id` which are assumed to terminate
+fn assumed_terminating_callees(ctx: &TranslationCtx, def_id: DefId) -> Vec<DefId> {
+    let tcx = ctx.tcx;
+    if !def_id.is_local()
+        || tcx.hir().maybe_body_owned_by(def_id.expect_local()).is_none()
+        || !termination::is_program(ctx, def_id)
+        || !termination::must_terminate(ctx, def_id)
+        || util::is_trusted(tcx, def_id)
+    {
+        return Vec::new();
+    }
+
+    let param_env = ctx.param_env(def_id);
+    termination::calls(ctx, def_id)
+        .into_iter()
+        .filter_map(|(callee, subst, _)| termination::resolve_callee(tcx, param_env, callee, subst))
+        .filter(|callee| {
+            termination::is_program(ctx, *callee) && termination::assumed_terminating(ctx, *callee)
+        })
+        .collect()
+}
+
+// The logic items used by a term
+fn items(term: &Term) -> Vec<DefId> {
+    struct Items(Vec<DefId>);
+
+    impl<'tcx> TermVisitor<'tcx> for Items {
+        fn visit_term(&mut self, term: &Term<'tcx>) {
+            if let TermKind::Call { id, .. } | TermKind::Item(id, _) = term.kind {
+                self.0.push(id);
+            }
+            super_visit_term(term, self)
+        }
+    }
+
+    let mut visitor = Items(Vec::new());
+    visitor.visit_term(term);
+    visitor.0
+}
+
+fn assumption(ctx: &TranslationCtx, def_id: DefId) -> Option<Assumption> {
+    let tcx = ctx.tcx;
+    if let Some(builtin) = util::get_builtin(tcx, def_id) {
+        Some(Assumption::Builtin(builtin))
+    } else if util::is_trusted(tcx, def_id) {
+        Some(Assumption::Trusted)
+    } else if !def_id.is_local() && ctx.extern_spec(def_id).is_some() {
+        Some(Assumption::ExternSpec)
+    } else if util::is_law(tcx, def_id) && tcx.trait_of_item(def_id).is_some() {
+        Some(Assumption::Law)
+    } else {
+        None
+    }
+}
+
+impl fmt::Display for TrustBase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.focus {
+            Some(focus) => {
+                writeln!(f, "Trust base of `{}` (items matching `{focus}`)", self.crate_name)?
+            }
+            None => writeln!(f, "Trust base of `{}`", self.crate_name)?,
+        }
+
+        if self.crates.is_empty() {
+            return writeln!(f, "no assumptions");
+        }
+
+        for (krate, items) in &self.crates {
+            writeln!(f, "crate `{krate}`")?;
+            for (path, assumption) in items {
+                writeln!(f, "  {path}: {assumption}")?;
+            }
+        }
+        Ok(())
+    }
+}
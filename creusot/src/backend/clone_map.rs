@@ -409,7 +409,9 @@ impl<'tcx> CloneMap<'tcx> {
                 continue;
             }
 
-            if traits::still_specializable(self.tcx, param_env, key.0, key.1) {
+            if traits::still_specializable(self.tcx, param_env, key.0, key.1)
+                || !util::is_transparent_from(ctx, key.0, self.self_id)
+            {
                 self.names[&key].opaque();
             }
 
@@ -1,7 +1,7 @@
 use super::clone_map::CloneMap;
 use crate::{
     backend::{
-        logic::spec_axiom,
+        logic::{program_symbol, spec_axiom},
         program::{closure_aux_defs, closure_type_use},
     },
     ctx::*,
@@ -47,6 +47,10 @@ pub(crate) fn interface_for<'tcx>(
             sig.retty = None;
             sig.contract = Contract::new();
             decls.push(Decl::ValDecl(util::item_type(ctx.tcx, def_id).val(sig)));
+            // Opaque functions are cloned through their interface in proofs
+            if util::is_opaque(ctx.tcx, def_id) {
+                decls.push(Decl::ValDecl(program_symbol(ctx, def_id, &sig_contract)));
+            }
 
             let has_axioms = !sig_contract.contract.ensures.is_empty();
             if has_axioms {
@@ -57,6 +61,9 @@ pub(crate) fn interface_for<'tcx>(
             let sig_contract = sig.clone();
             sig.contract = Contract::new();
             decls.push(Decl::ValDecl(util::item_type(ctx.tcx, def_id).val(sig)));
+            if util::is_opaque(ctx.tcx, def_id) {
+                decls.push(Decl::ValDecl(program_symbol(ctx, def_id, &sig_contract)));
+            }
 
             let has_axioms = !sig_contract.contract.ensures.is_empty();
             if has_axioms {
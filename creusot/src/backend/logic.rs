@@ -118,12 +118,7 @@ fn body_module<'tcx>(
     let mut names = CloneMap::new(ctx.tcx, def_id, CloneLevel::Stub);
 
     let mut sig = crate::util::signature_of(ctx, &mut names, def_id);
-    let mut val_sig = sig.clone();
-    val_sig.contract.variant = Vec::new();
-    let (val_args, val_binders) = binders_to_args(ctx, val_sig.args);
-    val_sig.contract.ensures =
-        vec![Exp::pure_var("result".into()).eq(Exp::pure_var(sig.name.clone()).app(val_args))];
-    val_sig.args = val_binders;
+    let val_sig = program_symbol(ctx, def_id, &sig).sig;
 
     if util::is_predicate(ctx.tcx, def_id) {
         sig.retty = None;
@@ -194,6 +189,23 @@ fn body_module<'tcx>(
     (Module { name, decls }, summary)
 }
 
+// The program function through which proofs call the logical function `def_id`
+pub(crate) fn program_symbol(ctx: &mut TranslationCtx, def_id: DefId, sig: &Signature) -> ValDecl {
+    let mut val_sig = sig.clone();
+    val_sig.contract.variant = Vec::new();
+    let (val_args, val_binders) = binders_to_args(ctx, val_sig.args);
+    val_sig.contract.ensures =
+        vec![Exp::pure_var("result".into()).eq(Exp::pure_var(sig.name.clone()).app(val_args))];
+    val_sig.args = val_binders;
+
+    // Calling a lemma directly provides its postcondition
+    if util::is_lemma(ctx.tcx, def_id) {
+        val_sig.contract.ensures.extend(sig.contract.ensures.iter().cloned());
+    }
+
+    ValDecl { sig: val_sig, ghost: false, val: true, kind: None }
+}
+
 pub(crate) fn stub_module(ctx: &mut TranslationCtx, def_id: DefId) -> Module {
     let mut names = CloneMap::new(ctx.tcx, def_id, CloneLevel::Stub);
     let mut sig = crate::util::signature_of(ctx, &mut names, def_id);
@@ -243,11 +255,16 @@ fn proof_module(ctx: &mut TranslationCtx, def_id: DefId) -> Option<Module> {
             sig.retty = None;
             Some(LetKind::Predicate)
         }
+        ItemType::Logic if util::is_lemma(ctx.tcx, def_id) => Some(LetKind::Lemma),
         ItemType::Logic => Some(LetKind::Function),
         _ => unreachable!(),
     };
 
-    decls.push(Decl::Let(LetDecl { sig, rec: true, ghost: true, body, kind }));
+    sig.attrs.extend(util::proof_attrs(ctx.tcx, def_id).unwrap_or_default());
+
+    // Lemmas are implicitly ghost in Why3
+    let ghost = !matches!(kind, Some(LetKind::Lemma));
+    decls.push(Decl::Let(LetDecl { sig, rec: true, ghost, body, kind }));
 
     let name = impl_name(ctx, def_id);
     Some(Module { name, decls })
@@ -273,7 +290,11 @@ pub(crate) fn spec_axiom(sig: &Signature) -> Axiom {
         .filter(|arg| &*arg.0 != "_")
         .collect();
 
-    let axiom = if args.is_empty() { condition } else { Exp::Forall(args, Box::new(condition)) };
+    let axiom = if args.is_empty() {
+        condition
+    } else {
+        Exp::Forall(args, Vec::new(), Box::new(condition))
+    };
 
     Axiom { name: format!("{}_spec", &*sig.name).into(), axiom }
 }
@@ -304,7 +325,11 @@ fn definition_axiom(sig: &Signature, body: Exp) -> Axiom {
 
     let args: Vec<_> = sig.args.clone().into_iter().flat_map(|b| b.var_type_pairs()).collect();
 
-    let axiom = if args.is_empty() { condition } else { Exp::Forall(args, Box::new(condition)) };
+    let axiom = if args.is_empty() {
+        condition
+    } else {
+        Exp::Forall(args, Vec::new(), Box::new(condition))
+    };
 
     Axiom { name: "def".into(), axiom }
 }
@@ -10,12 +10,17 @@ use crate::{
         binop_to_binop,
         fmir::{self, Block, Branches, Expr, RValue, Statement, Terminator},
         function::{closure_contract, closure_generic_decls, promoted, ClosureContract},
+        pearlite::{Term, TermKind},
         specification::{lower_impure, lower_pure},
         unop_to_unop,
     },
     util::{self, is_ghost_closure, module_name, signature_of, ItemType},
 };
-use rustc_hir::{def::DefKind, def_id::DefId, Unsafety};
+use rustc_hir::{
+    def::{DefKind, Namespace},
+    def_id::DefId,
+    Unsafety,
+};
 use rustc_index::vec::IndexVec;
 use rustc_middle::{
     mir::{self, BasicBlock, BinOp, MirPass, Place},
@@ -25,7 +30,7 @@ use rustc_mir_transform::{cleanup_post_borrowck::CleanupPostBorrowck, simplify::
 use rustc_span::DUMMY_SP;
 use rustc_type_ir::{IntTy, UintTy};
 use why3::{
-    declaration::{self, CfgFunction, Decl, LetDecl, LetKind, Module, Predicate, Use},
+    declaration::{self, CfgFunction, Decl, Goal, LetDecl, LetKind, Module, Predicate, Use},
     exp::{Constant, Exp, Pattern},
     mlcfg,
     mlcfg::BlockId,
@@ -168,7 +173,11 @@ pub(crate) fn translate_function<'tcx, 'sess>(
 
     let promoteds = lower_promoted(ctx, &mut names, def_id, &*promoted.borrow());
 
-    let (clones, _) = names.to_clones(ctx);
+    let requires_sat =
+        if ctx.opts.check_requires_sat { requires_sat_goal(ctx, &mut names, def_id) } else { None };
+
+    let (clones, summary) = names.to_clones(ctx);
+    ctx.add_body_dependencies(def_id, summary);
 
     let decls = closure_generic_decls(ctx.tcx, def_id)
         .chain(closure_type_use(ctx, def_id))
@@ -176,12 +185,48 @@ pub(crate) fn translate_function<'tcx, 'sess>(
         .chain(closure_defs)
         .chain(promoteds)
         .chain(std::iter::once(body))
+        .chain(requires_sat)
         .collect();
 
     let name = module_name(ctx.tcx, def_id);
     Some(Module { name, decls })
 }
 
+// A goal which fails when no arguments satisfy the preconditions of `def_id`, in which case its
+// postconditions hold vacuously.
+fn requires_sat_goal<'tcx>(
+    ctx: &mut TranslationCtx<'tcx>,
+    names: &mut CloneMap<'tcx>,
+    def_id: DefId,
+) -> Option<Decl> {
+    let sig = ctx.sig(def_id).clone();
+    if sig.contract.requires.is_empty() {
+        return None;
+    }
+
+    let binders = sig
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(ix, &(name, _, ty))| {
+            (if name.is_empty() { util::anonymous_param_symbol(ix) } else { name }, ty)
+        })
+        .collect();
+    let body = Box::new(sig.contract.requires_conj(ctx.tcx));
+    let exists = Term {
+        ty: ctx.tcx.types.bool,
+        span: body.span,
+        kind: TermKind::Exists { binders, triggers: Vec::new(), body },
+    };
+
+    let name = util::item_name(ctx.tcx, def_id, Namespace::ValueNS);
+    Some(Decl::Goal(Goal {
+        name: format!("{}_requires_sat", &*name).into(),
+        attrs: util::proof_attrs(ctx.tcx, def_id).unwrap_or_default(),
+        goal: lower_pure(ctx, names, exists),
+    }))
+}
+
 // According to @oli-obk, promoted bodies are:
 // > it's completely linear, not even conditions or asserts inside. we should probably document all that with validation
 // On this supposition we can simplify the translation *dramatically* and produce why3 constants
@@ -231,7 +276,9 @@ fn lower_promoted<'tcx>(
                     body: Box::new(acc),
                 },
                 why3::mlcfg::Statement::Assume(_) => acc,
-                why3::mlcfg::Statement::Invariant(_, _) => todo!(),
+                why3::mlcfg::Statement::Invariant(_, _) | why3::mlcfg::Statement::Variant(_) => {
+                    todo!()
+                }
                 why3::mlcfg::Statement::Assert(_) => {
                     ctx.crash_and_error(ctx.def_span(def_id), "unsupported promoted constant")
                 }
@@ -292,6 +339,8 @@ pub fn to_why<'tcx>(
         sig.attrs.push(declaration::Attribute::Attr("cfg:stackify".into()));
         sig.attrs.push(declaration::Attribute::Attr("cfg:subregion_analysis".into()));
     };
+    let root = ctx.typeck_root_def_id(def_id);
+    sig.attrs.extend(util::proof_attrs(ctx.tcx, root).unwrap_or_default());
 
     let func = Decl::CfgDecl(CfgFunction {
         sig,
@@ -664,11 +713,19 @@ impl<'tcx> Statement<'tcx> {
             Statement::Assertion(a) => {
                 vec![mlcfg::Statement::Assert(lower_pure(ctx, names, a))]
             }
+            Statement::Assume(a) => vec![mlcfg::Statement::Assume(lower_pure(ctx, names, a))],
 
             Statement::Invariant(nm, inv) => vec![mlcfg::Statement::Invariant(
                 nm.to_string().into(),
                 lower_pure(ctx, names, inv),
             )],
+            Statement::Variant(var) => vec![mlcfg::Statement::Variant(lower_pure(ctx, names, var))],
+            Statement::Snapshot(id, term) => {
+                vec![mlcfg::Statement::Assign {
+                    lhs: id.ident(),
+                    rhs: lower_pure(ctx, names, term),
+                }]
+            }
         }
     }
 }
@@ -1,14 +1,14 @@
 use crate::{
     backend::ty::{intty_to_ty, translate_ty, uintty_to_ty},
     ctx::*,
-    pearlite::{self, Literal, Pattern, Term, TermKind},
+    pearlite::{self, Literal, Pattern, Term, TermKind, Trigger},
     util,
     util::get_builtin,
 };
 use rustc_hir::Unsafety;
 use rustc_middle::ty::{EarlyBinder, Ty, TyKind};
 use why3::{
-    exp::{BinOp, Binder, Constant, Exp, Pattern as Pat, Purity},
+    exp::{self, BinOp, Binder, Constant, Exp, Pattern as Pat, Purity},
     ty::Type,
     Ident, QName,
 };
@@ -157,22 +157,18 @@ impl<'tcx> Lower<'_, 'tcx> {
                     }
                 })
             }
-            TermKind::Forall { binder, box body } => {
-                let ty = translate_ty(self.ctx, self.names, rustc_span::DUMMY_SP, binder.1);
+            TermKind::Forall { binders, triggers, box body } => {
+                let binders = self.lower_binders(binders);
                 self.pure_exp(|this| {
-                    Exp::Forall(
-                        vec![(binder.0.to_string().into(), ty)],
-                        Box::new(this.lower_term(body)),
-                    )
+                    let triggers = this.lower_triggers(triggers);
+                    Exp::Forall(binders, triggers, Box::new(this.lower_term(body)))
                 })
             }
-            TermKind::Exists { binder, box body } => {
-                let ty = translate_ty(self.ctx, self.names, rustc_span::DUMMY_SP, binder.1);
+            TermKind::Exists { binders, triggers, box body } => {
+                let binders = self.lower_binders(binders);
                 self.pure_exp(|this| {
-                    Exp::Exists(
-                        vec![(binder.0.to_string().into(), ty)],
-                        Box::new(this.lower_term(body)),
-                    )
+                    let triggers = this.lower_triggers(triggers);
+                    Exp::Exists(binders, triggers, Box::new(this.lower_term(body)))
                 })
             }
             TermKind::Constructor { adt, variant, fields } => {
@@ -199,6 +195,13 @@ impl<'tcx> Lower<'_, 'tcx> {
                 self.pure_exp(|this| this.lower_term(lhs).implies(this.lower_term(rhs)))
             }
             TermKind::Old { box term } => Exp::Old(Box::new(self.lower_term(term))),
+            TermKind::Assign { .. } => self
+                .ctx
+                .crash_and_error(term.span, "assignments can only be used in `ghost!` blocks"),
+            TermKind::At { .. } => self.ctx.crash_and_error(
+                term.span,
+                "`at!` and `old!` can only be used in loop invariants and `proof_assert!`",
+            ),
             TermKind::Match { box scrutinee, mut arms } => {
                 if scrutinee.ty.peel_refs().is_bool() {
                     let true_br = if let Pattern::Boolean(true) = arms[0].0 {
@@ -307,6 +310,17 @@ impl<'tcx> Lower<'_, 'tcx> {
         }
     }
 
+    fn lower_binders(&mut self, binders: Vec<pearlite::Binder<'tcx>>) -> Vec<(Ident, Type)> {
+        binders.into_iter().map(|(name, ty)| (name.to_string().into(), self.lower_ty(ty))).collect()
+    }
+
+    fn lower_triggers(&mut self, triggers: Vec<Trigger<'tcx>>) -> Vec<exp::Trigger> {
+        triggers
+            .into_iter()
+            .map(|t| exp::Trigger(t.0.into_iter().map(|t| self.lower_term(t)).collect()))
+            .collect()
+    }
+
     fn lower_pat(&mut self, pat: Pattern<'tcx>) -> Pat {
         match pat {
             Pattern::Constructor { adt, variant: _, fields, substs } => {
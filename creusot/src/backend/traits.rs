@@ -24,6 +24,7 @@ pub(crate) fn lower_impl<'tcx>(ctx: &mut TranslationCtx<'tcx>, def_id: DefId) ->
         impl_decls.extend(own_generic_decls_for(tcx, refn.impl_.0));
         impl_decls.push(Decl::Goal(Goal {
             name: format!("{}_refn", &*name).into(),
+            attrs: util::proof_attrs(tcx, refn.impl_.0).unwrap_or_default(),
             goal: lower_pure(ctx, &mut names, refn.refn.clone()),
         }));
     }
@@ -275,6 +275,20 @@ pub(crate) fn translate_ty_param(p: Symbol) -> Ident {
     Ident::build(&p.to_string().to_lowercase())
 }
 
+// External types whose fields mention associated types cannot be declared, their fields are
+// not visible anyway so we translate them as opaque types.
+fn is_opaque_extern(tcx: TyCtxt, did: DefId) -> bool {
+    if did.is_local() {
+        return false;
+    }
+    let substs = InternalSubsts::identity_for_item(tcx, did);
+    tcx.adt_def(did).all_fields().any(|field| {
+        field.ty(tcx, substs).walk().any(|arg| {
+            matches!(arg.unpack(), GenericArgKind::Type(ty) if matches!(ty.kind(), Alias(AliasKind::Projection, _)))
+        })
+    })
+}
+
 // Translate a Rust type declation to an ML one
 // Rust tuple-like types are translated as one would expect, to product types in WhyML
 // However, Rust struct types are *not* translated to WhyML records, instead we 'forget' the field names
@@ -302,7 +316,7 @@ pub(crate) fn translate_tydecl(ctx: &mut TranslationCtx<'_>, did: DefId) {
     let name = module_name(ctx.tcx, repr);
 
     // Trusted types (opaque)
-    if util::is_trusted(ctx.tcx, repr) {
+    if util::is_trusted(ctx.tcx, repr) || is_opaque_extern(ctx.tcx, repr) {
         if bg.len() > 1 {
             ctx.crash_and_error(span, "cannot mark mutually recursive types as trusted");
         }
@@ -1,5 +1,6 @@
 use rustc_driver::{Callbacks, Compilation};
 use rustc_interface::{interface::Compiler, Config, Queries};
+use rustc_span::Symbol;
 
 use crate::{cleanup_spec_closures::*, options::Options};
 
@@ -35,6 +36,13 @@ impl Callbacks for ToWhy {
     }
 
     fn after_expansion<'tcx>(&mut self, c: &Compiler, queries: &'tcx Queries<'tcx>) -> Compilation {
+        // Have cargo rerun Creusot on this crate when Creusot itself changes, as its metadata can
+        // only be read back by the build which produced it
+        if self.opts.in_cargo && let Ok(exe) = std::env::current_exe() {
+            let exe = Symbol::intern(&exe.to_string_lossy());
+            c.session().parse_sess.file_depinfo.borrow_mut().insert(exe);
+        }
+
         queries.global_ctxt().unwrap();
         let _ = queries.global_ctxt().unwrap().enter(|tcx| {
             let mut ctx = ctx::TranslationCtx::new(tcx, self.opts.clone());
@@ -0,0 +1,137 @@
+// Summarizes how much of a crate is verified, trusted or left unspecified.
+
+use crate::{
+    ctx::{ItemType, TranslationCtx},
+    util,
+};
+use rustc_hir::def::DefKind;
+use rustc_span::def_id::{DefId, LOCAL_CRATE};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+#[derive(Default)]
+struct ModuleCoverage {
+    // Program functions with a contract
+    verified: usize,
+    // Program functions without any contract, for which only safety is proved
+    unspecified: usize,
+    trusted: usize,
+    logic: usize,
+    // Functions which Creusot skipped
+    unsupported: usize,
+}
+
+impl ModuleCoverage {
+    fn add(&mut self, other: &ModuleCoverage) {
+        self.verified += other.verified;
+        self.unspecified += other.unspecified;
+        self.trusted += other.trusted;
+        self.logic += other.logic;
+        self.unsupported += other.unsupported;
+    }
+}
+
+pub(crate) struct Coverage {
+    crate_name: String,
+    modules: BTreeMap<String, ModuleCoverage>,
+    // External functions specified by an `extern_spec!`, along with the local functions calling them
+    extern_specs: BTreeMap<String, BTreeSet<String>>,
+}
+
+pub(crate) fn coverage(ctx: &mut TranslationCtx) -> Coverage {
+    let tcx = ctx.tcx;
+    let mut cov = Coverage {
+        crate_name: tcx.crate_name(LOCAL_CRATE).to_string(),
+        modules: BTreeMap::new(),
+        extern_specs: BTreeMap::new(),
+    };
+
+    for local_id in tcx.hir().body_owners() {
+        let def_id = local_id.to_def_id();
+        if !matches!(tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+            || util::is_extern_spec(tcx, def_id)
+        {
+            continue;
+        }
+
+        let module = tcx.def_path_str(tcx.parent_module_from_def_id(local_id).to_def_id());
+        let module = if module.is_empty() { cov.crate_name.clone() } else { module };
+        let entry = cov.modules.entry(module).or_default();
+
+        if !util::should_translate(tcx, def_id) || ctx.item(def_id).is_none() {
+            entry.unsupported += 1;
+            continue;
+        }
+
+        match util::item_type(tcx, def_id) {
+            ItemType::Logic | ItemType::Predicate => entry.logic += 1,
+            _ if util::is_trusted(tcx, def_id) => entry.trusted += 1,
+            _ if ctx.sig(def_id).contract.is_empty() => entry.unspecified += 1,
+            _ => entry.verified += 1,
+        }
+
+        let caller = tcx.def_path_str(def_id);
+        for callee in calls(ctx, def_id) {
+            if !callee.is_local() && ctx.extern_spec(callee).is_some() {
+                cov.extern_specs
+                    .entry(tcx.def_path_str(callee))
+                    .or_default()
+                    .insert(caller.clone());
+            }
+        }
+    }
+
+    cov
+}
+
+// The functions called by the body of a program function
+fn calls(ctx: &mut TranslationCtx, def_id: DefId) -> BTreeSet<DefId> {
+    if util::is_trusted(ctx.tcx, def_id) || !util::has_body(ctx, def_id) {
+        return BTreeSet::new();
+    }
+
+    let Some(body) = ctx.fmir_body(def_id) else { return BTreeSet::new() };
+    body.calls().into_iter().map(|(id, _, _)| id).collect()
+}
+
+impl fmt::Display for Coverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const COLUMNS: [&str; 5] = ["verified", "unspecified", "trusted", "logic", "unsupported"];
+
+        let mut total = ModuleCoverage::default();
+        self.modules.values().for_each(|m| total.add(m));
+
+        let width = self.modules.keys().map(|m| m.len()).chain([5]).max().unwrap_or_default();
+        let row = |f: &mut fmt::Formatter<'_>, name: &str, m: &ModuleCoverage| {
+            let counts = [m.verified, m.unspecified, m.trusted, m.logic, m.unsupported];
+            write!(f, "{name:width$}")?;
+            for (count, column) in counts.iter().zip(COLUMNS) {
+                write!(f, "  {count:>len$}", len = column.len())?;
+            }
+            writeln!(f)
+        };
+
+        writeln!(f, "Specification coverage of `{}`", self.crate_name)?;
+        write!(f, "{:width$}", "module")?;
+        for column in COLUMNS {
+            write!(f, "  {column}")?;
+        }
+        writeln!(f)?;
+        for (name, m) in &self.modules {
+            row(f, name, m)?;
+        }
+        row(f, "total", &total)?;
+
+        if !self.extern_specs.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "External functions specified by `extern_spec!`")?;
+            for (callee, callers) in &self.extern_specs {
+                let callers: Vec<_> = callers.iter().map(|c| format!("`{c}`")).collect();
+                writeln!(f, "{callee} (called by {})", callers.join(", "))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -53,6 +53,8 @@ pub struct TranslationCtx<'tcx> {
     ty_binding_groups: HashMap<DefId, IndexSet<DefId>>,
     functions: IndexMap<DefId, TranslatedItem>,
     dependencies: IndexMap<DefId, CloneSummary<'tcx>>,
+    // Clones made by the bodies of program functions, which are not visible to their callers
+    body_dependencies: IndexMap<DefId, CloneSummary<'tcx>>,
     laws: IndexMap<DefId, Vec<DefId>>,
     fmir_body: IndexMap<DefId, fmir::Body<'tcx>>,
     terms: IndexMap<DefId, Term<'tcx>>,
@@ -84,6 +86,7 @@ impl<'tcx, 'sess> TranslationCtx<'tcx> {
             in_translation: Default::default(),
             functions: Default::default(),
             dependencies: Default::default(),
+            body_dependencies: Default::default(),
             externs: Default::default(),
             terms: Default::default(),
             creusot_items,
@@ -421,6 +424,14 @@ impl<'tcx, 'sess> TranslationCtx<'tcx> {
         self.dependencies.get(&def_id)
     }
 
+    pub(crate) fn body_dependencies(&self, def_id: DefId) -> Option<&CloneSummary<'tcx>> {
+        self.body_dependencies.get(&def_id)
+    }
+
+    pub(crate) fn add_body_dependencies(&mut self, def_id: DefId, deps: CloneSummary<'tcx>) {
+        self.body_dependencies.insert(def_id, deps);
+    }
+
     pub(crate) fn item(&self, def_id: DefId) -> Option<&TranslatedItem> {
         let def_id = self.representative_type.get(&def_id).unwrap_or(&def_id);
         self.functions.get(def_id)
@@ -2,44 +2,139 @@ use indexmap::{IndexMap, IndexSet};
 
 use crate::{
     ctx::TranslationCtx,
-    pearlite::Term,
-    translation::specification::inv_subst,
+    pearlite::{
+        super_visit_mut_term, super_visit_term, Pattern, Term, TermKind, TermVisitor,
+        TermVisitorMut,
+    },
+    translation::{
+        fmir::{self, RValue},
+        function::LocalIdent,
+        specification::{inv_subst, visible_locals},
+    },
     util::{self, is_ghost_closure},
 };
 use rustc_data_structures::graph::WithSuccessors;
 use rustc_hir::def_id::DefId;
 use rustc_middle::{
-    mir::{visit::Visitor, AggregateKind, BasicBlock, Body, Location, Operand, Rvalue},
-    ty::{TyCtxt, TyKind},
+    mir::{
+        visit::Visitor, AggregateKind, BasicBlock, Body, Local, Location, Operand, Rvalue,
+        START_BLOCK,
+    },
+    ty::{Ty, TyCtxt, TyKind},
 };
-use rustc_span::Symbol;
+use rustc_span::{Span, Symbol};
+use std::collections::{HashMap, HashSet};
+
+/// A specification attached to the header of a loop
+pub(crate) enum LoopSpec<'tcx> {
+    Invariant(Symbol, Term<'tcx>),
+    Variant(Term<'tcx>),
+}
+
+impl<'tcx> LoopSpec<'tcx> {
+    fn subst(&mut self, subst: &std::collections::HashMap<Symbol, Term<'tcx>>) {
+        match self {
+            LoopSpec::Invariant(_, term) | LoopSpec::Variant(term) => term.subst(subst),
+        }
+    }
+}
+
+/// The values recorded for the uses of `at!` and `old!` in loop specifications and assertions
+#[derive(Default)]
+pub(crate) struct Snapshots<'tcx> {
+    /// Fresh locals holding the recorded values
+    pub(crate) locals: Vec<(LocalIdent, Span, Ty<'tcx>)>,
+    /// Values recorded where a spec closure (a `label!`, invariant or variant) is created
+    pub(crate) at_closure: IndexMap<DefId, Vec<(LocalIdent, Term<'tcx>)>>,
+    /// Values recorded at the start of the function
+    pub(crate) at_entry: Vec<(LocalIdent, Term<'tcx>)>,
+}
+
+impl<'tcx> Snapshots<'tcx> {
+    fn fresh(&mut self, body: &Body, name: Option<Symbol>, span: Span, ty: Ty<'tcx>) -> LocalIdent {
+        let local = Local::from_usize(body.local_decls.len() + self.locals.len());
+        let ident = match name {
+            Some(name) => LocalIdent::dbg_raw(local, name),
+            None => LocalIdent::anon(local),
+        };
+        self.locals.push((ident.clone(), span, ty));
+        ident
+    }
+}
+
+/// The statements of a `ghost!` block, followed by its value
+pub(crate) struct GhostBlock<'tcx> {
+    pub(crate) stmts: Vec<fmir::Statement<'tcx>>,
+    pub(crate) value: Term<'tcx>,
+}
+
+/// The specifications found in the body of a function, placed where they apply
+pub(crate) struct SpecClosures<'tcx> {
+    pub(crate) invariants: IndexMap<BasicBlock, Vec<LoopSpec<'tcx>>>,
+    pub(crate) assertions: IndexMap<DefId, Term<'tcx>>,
+    pub(crate) ghosts: IndexMap<DefId, GhostBlock<'tcx>>,
+    pub(crate) snapshots: Snapshots<'tcx>,
+}
 
 pub(crate) fn corrected_invariant_names_and_locations<'tcx>(
     ctx: &mut TranslationCtx<'tcx>,
     def_id: DefId,
     body: &Body<'tcx>,
-) -> (IndexMap<BasicBlock, Vec<(Symbol, Term<'tcx>)>>, IndexMap<DefId, Term<'tcx>>) {
+) -> SpecClosures<'tcx> {
     let mut visitor = InvariantClosures::new(ctx.tcx, def_id);
     visitor.visit_body(&body);
 
+    let mut ass_loc = ClosureLocations { locations: IndexMap::new() };
+    ass_loc.visit_body(body);
+    let closure_locations = ass_loc.locations;
+
+    let mut labels = IndexMap::new();
+    for clos in &visitor.closures {
+        if let Some(label) = util::label_name(ctx.tcx, *clos) {
+            if labels.insert(label, *clos).is_some() {
+                ctx.error(ctx.def_span(*clos), &format!("duplicate label `{label}`"));
+            }
+        }
+    }
+
     let mut assertions: IndexMap<_, _> = Default::default();
     // let mut ghosts: IndexMap<_, _> = Default::default();
     let mut invariants: IndexMap<_, _> = Default::default();
+    let mut ghosts: IndexMap<_, _> = Default::default();
+    let mut snapshots = Snapshots::default();
+    let mut hoist = |ctx: &TranslationCtx<'tcx>, old: Option<DefId>, term: &mut Term<'tcx>| {
+        let mut hoist = HoistSnapshots {
+            ctx,
+            body,
+            labels: &labels,
+            locations: &closure_locations,
+            old,
+            bound: HashSet::new(),
+            snapshots: &mut snapshots,
+        };
+        hoist.visit_mut_term(term);
+    };
 
     for clos in visitor.closures.into_iter() {
         if let Some(name) = util::invariant_name(ctx.tcx, clos) {
-            let term = ctx.term(clos).unwrap().clone();
+            let mut term = ctx.term(clos).unwrap().clone();
+            hoist(ctx, Some(clos), &mut term);
+
+            invariants.insert(clos, LoopSpec::Invariant(name, term));
+        } else if util::is_loop_variant(ctx.tcx, clos) {
+            let mut term = ctx.term(clos).unwrap().clone();
+            hoist(ctx, Some(clos), &mut term);
 
-            invariants.insert(clos, (name, term));
+            invariants.insert(clos, LoopSpec::Variant(term));
         } else if util::is_assertion(ctx.tcx, clos) {
-            let term = ctx.term(clos).unwrap().clone();
+            let mut term = ctx.term(clos).unwrap().clone();
+            hoist(ctx, None, &mut term);
 
             assertions.insert(clos, term);
         } else if util::is_ghost(ctx.tcx, clos) {
             let term = ctx.term(clos).unwrap().clone();
 
-            // A hack should probably be separately tracked
-            assertions.insert(clos, term);
+            ghosts.insert(clos, term);
         }
     }
 
@@ -53,7 +148,7 @@ pub(crate) fn corrected_invariant_names_and_locations<'tcx>(
                 .map(|id| {
                     let mut inv = invariants.remove(&id.1).unwrap();
                     let inv_subst = inv_subst(body, id.0);
-                    inv.1.subst(&inv_subst);
+                    inv.subst(&inv_subst);
                     inv
                 })
                 .collect();
@@ -62,22 +157,202 @@ pub(crate) fn corrected_invariant_names_and_locations<'tcx>(
         })
         .collect();
 
-    let mut ass_loc = ClosureLocations { locations: IndexMap::new() };
-    ass_loc.visit_body(body);
-    let locations = ass_loc.locations;
-
     let assertions = assertions
         .into_iter()
         .map(|mut ass| {
-            let inv_subst = inv_subst(body, locations[&ass.0]);
+            let inv_subst = inv_subst(body, closure_locations[&ass.0]);
 
             ass.1.subst(&inv_subst);
             ass
         })
         .collect();
 
+    let ghosts = ghosts
+        .into_iter()
+        .map(|(clos, term)| {
+            let loc = closure_locations[&clos];
+            let mut lower = LowerGhost {
+                ctx,
+                body,
+                locals: visible_locals(body, loc),
+                subst: inv_subst(body, loc),
+                stmts: Vec::new(),
+                snapshots: &mut snapshots,
+            };
+            let mut value = lower.lower(term);
+            value.subst(&lower.subst);
+            (clos, GhostBlock { stmts: lower.stmts, value })
+        })
+        .collect();
+
     assert!(invariants.is_empty());
-    (correct_inv, assertions)
+    SpecClosures { invariants: correct_inv, assertions, ghosts, snapshots }
+}
+
+// Turns the assignments of a `ghost!` block into statements. The variables it binds before an
+// assignment are recorded in fresh locals.
+struct LowerGhost<'a, 'tcx> {
+    ctx: &'a TranslationCtx<'tcx>,
+    body: &'a Body<'tcx>,
+    locals: HashMap<Symbol, Local>,
+    subst: HashMap<Symbol, Term<'tcx>>,
+    stmts: Vec<fmir::Statement<'tcx>>,
+    snapshots: &'a mut Snapshots<'tcx>,
+}
+
+impl<'a, 'tcx> LowerGhost<'a, 'tcx> {
+    fn lower(&mut self, term: Term<'tcx>) -> Term<'tcx> {
+        if !assigns(&term) {
+            return term;
+        }
+
+        let Term { ty, span, kind } = term;
+        match kind {
+            TermKind::Assign { var, rhs } => {
+                let Some(local) = self.locals.get(&var).copied() else {
+                    self.ctx.crash_and_error(span, &format!("cannot assign to `{var}` in ghost code"))
+                };
+                let mut rhs = self.lower(*rhs);
+                rhs.subst(&self.subst);
+                self.stmts.push(fmir::Statement::Assignment(local.into(), RValue::Ghost(rhs)));
+                Term { ty, span, kind: TermKind::Tuple { fields: Vec::new() } }
+            }
+            TermKind::Let { pattern, arg, body } => {
+                let mut arg = self.lower(*arg);
+                let name = match pattern {
+                    Pattern::Binder(name) => Some(name),
+                    Pattern::Wildcard => None,
+                    _ => self.ctx.crash_and_error(
+                        arg.span,
+                        "only variables can be bound before an assignment in a `ghost!` block",
+                    ),
+                };
+                let unit = matches!(&arg.kind, TermKind::Tuple { fields } if fields.is_empty());
+                if name.is_some() || !unit {
+                    arg.subst(&self.subst);
+                    let ident = self.snapshots.fresh(self.body, name, arg.span, arg.ty);
+                    if let Some(name) = name {
+                        self.subst.insert(
+                            name,
+                            Term {
+                                ty: arg.ty,
+                                span: arg.span,
+                                kind: TermKind::Var(ident.symbol()),
+                            },
+                        );
+                    }
+                    self.stmts.push(fmir::Statement::Snapshot(ident, arg));
+                }
+                self.lower(*body)
+            }
+            TermKind::Call { id, subst, fun, args } => {
+                let args = args.into_iter().map(|arg| self.lower(arg)).collect();
+                Term { ty, span, kind: TermKind::Call { id, subst, fun, args } }
+            }
+            _ => self.ctx.crash_and_error(
+                span,
+                "assignments in a `ghost!` block must be statements of the block",
+            ),
+        }
+    }
+}
+
+fn assigns(term: &Term) -> bool {
+    struct Assigns(bool);
+
+    impl<'tcx> TermVisitor<'tcx> for Assigns {
+        fn visit_term(&mut self, term: &Term<'tcx>) {
+            match term.kind {
+                TermKind::Assign { .. } => self.0 = true,
+                _ => super_visit_term(term, self),
+            }
+        }
+    }
+
+    let mut visitor = Assigns(false);
+    visitor.visit_term(term);
+    visitor.0
+}
+
+// Replaces the uses of `at!` and `old!` by fresh locals, recording the values they should hold
+struct HoistSnapshots<'a, 'tcx> {
+    ctx: &'a TranslationCtx<'tcx>,
+    body: &'a Body<'tcx>,
+    labels: &'a IndexMap<Symbol, DefId>,
+    locations: &'a IndexMap<DefId, Location>,
+    // The closure before which `old!` is recorded, or `None` for the start of the function
+    old: Option<DefId>,
+    // Variables bound by the specification itself, which have no value to record
+    bound: HashSet<Symbol>,
+    snapshots: &'a mut Snapshots<'tcx>,
+}
+
+impl<'a, 'tcx> TermVisitorMut<'tcx> for HoistSnapshots<'a, 'tcx> {
+    fn visit_mut_term(&mut self, term: &mut Term<'tcx>) {
+        let outer = self.bound.clone();
+        match &mut term.kind {
+            TermKind::At { label, term: inner } => {
+                let (closure, label) = match label {
+                    Some(label) => match self.labels.get(label) {
+                        Some(closure) => (Some(*closure), *label),
+                        None => {
+                            self.ctx.crash_and_error(term.span, &format!("unknown label `{label}`"))
+                        }
+                    },
+                    None => (self.old, Symbol::intern("old")),
+                };
+
+                if mentions_any(inner, &self.bound) {
+                    self.ctx.crash_and_error(
+                        term.span,
+                        "`at!` and `old!` cannot refer to variables bound in the specification",
+                    );
+                }
+
+                let loc = closure.map_or(START_BLOCK.start_location(), |c| self.locations[&c]);
+                let mut inner = (**inner).clone();
+                inner.subst(&inv_subst(self.body, loc));
+
+                let ident = self.snapshots.fresh(self.body, Some(label), term.span, term.ty);
+                match closure {
+                    Some(c) => self.snapshots.at_closure.entry(c).or_default(),
+                    None => &mut self.snapshots.at_entry,
+                }
+                .push((ident.clone(), inner));
+
+                term.kind = TermKind::Var(ident.symbol());
+                return;
+            }
+            TermKind::Forall { binders, .. } | TermKind::Exists { binders, .. } => {
+                self.bound.extend(binders.iter().map(|b| b.0))
+            }
+            TermKind::Match { arms, .. } => {
+                arms.iter().for_each(|(pat, _)| pat.binds(&mut self.bound))
+            }
+            TermKind::Let { pattern, .. } => pattern.binds(&mut self.bound),
+            TermKind::Closure { args, .. } => args.iter().for_each(|a| a.binds(&mut self.bound)),
+            _ => {}
+        }
+        super_visit_mut_term(term, self);
+        self.bound = outer;
+    }
+}
+
+fn mentions_any(term: &Term, vars: &HashSet<Symbol>) -> bool {
+    struct Mentions<'a>(&'a HashSet<Symbol>, bool);
+
+    impl<'a, 'tcx> TermVisitor<'tcx> for Mentions<'a> {
+        fn visit_term(&mut self, term: &Term<'tcx>) {
+            match term.kind {
+                TermKind::Var(v) if self.0.contains(&v) => self.1 = true,
+                _ => super_visit_term(term, self),
+            }
+        }
+    }
+
+    let mut visitor = Mentions(vars, false);
+    visitor.visit_term(term);
+    visitor.1
 }
 
 // Collect the closures in thir, so that we can do typechecking ourselves, and
@@ -141,7 +416,7 @@ struct InvariantLocations<'tcx> {
 impl<'tcx> Visitor<'tcx> for InvariantLocations<'tcx> {
     fn visit_rvalue(&mut self, rvalue: &Rvalue<'tcx>, loc: Location) {
         if let Rvalue::Aggregate(box AggregateKind::Closure(id, _), _) = rvalue {
-            if util::is_invariant(self.tcx, *id) {
+            if util::is_invariant(self.tcx, *id) || util::is_loop_variant(self.tcx, *id) {
                 self.invariants.insert(loc, *id);
             }
         }
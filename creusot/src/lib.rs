@@ -1,6 +1,6 @@
 #![feature(rustc_private, register_tool)]
 #![feature(box_patterns, control_flow_enum, drain_filter)]
-#![feature(let_chains, never_type, try_blocks)]
+#![feature(let_chains, never_type, once_cell, try_blocks)]
 
 #[macro_use]
 extern crate log;
@@ -29,9 +29,11 @@ extern crate rustc_trait_selection;
 extern crate rustc_type_ir;
 
 mod analysis;
+pub(crate) mod audit;
 pub(crate) mod backend;
 pub mod callbacks;
 mod cleanup_spec_closures;
+pub(crate) mod coverage;
 pub(crate) mod creusot_items;
 pub(crate) mod ctx;
 
@@ -41,9 +43,11 @@ pub(crate) mod debug;
 mod extended_location;
 mod gather_spec_closures;
 pub mod options;
+pub(crate) mod panics;
 mod resolve;
 // #[allow(dead_code)]
 mod rustc_extensions;
+pub(crate) mod termination;
 mod translation;
 pub(crate) mod util;
 use translation::*;
@@ -1,13 +1,29 @@
 mod experimental_types;
 mod resolve_trait;
+mod specifications;
+mod unused_logic;
 
 pub fn register_lints(_sess: &Session, store: &mut LintStore) {
-    store.register_lints(&[EXPERIMENTAL, RESOLVE_TRAIT]);
+    store.register_lints(&[
+        EXPERIMENTAL,
+        RESOLVE_TRAIT,
+        CONTRADICTORY_REQUIRES,
+        UNCONSTRAINED_RESULT,
+        TRUSTED_WITHOUT_CONTRACT,
+        UNUSED_LOGIC,
+    ]);
     store.register_late_pass(move |_| Box::new(experimental_types::Experimental {}));
     store.register_late_pass(move |_| Box::new(resolve_trait::ResolveTrait {}));
+    store.register_late_pass(move |_| Box::<unused_logic::UnusedLogic>::default());
 }
 
 use rustc_lint::LintStore;
 use rustc_session::Session;
 
-use self::{experimental_types::EXPERIMENTAL, resolve_trait::RESOLVE_TRAIT};
+pub(crate) use self::specifications::lint_contract;
+use self::{
+    experimental_types::EXPERIMENTAL,
+    resolve_trait::RESOLVE_TRAIT,
+    specifications::{CONTRADICTORY_REQUIRES, TRUSTED_WITHOUT_CONTRACT, UNCONSTRAINED_RESULT},
+    unused_logic::UNUSED_LOGIC,
+};
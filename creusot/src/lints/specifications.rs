@@ -0,0 +1,161 @@
+use crate::{
+    ctx::{ItemType, TranslationCtx},
+    translation::pearlite::{super_visit_term, BinOp, Literal, Term, TermKind, TermVisitor, UnOp},
+    util,
+};
+use rustc_hir::{def::DefKind, def_id::DefId};
+use rustc_session::declare_tool_lint;
+use rustc_span::Symbol;
+
+declare_tool_lint! {
+    /// Detects preconditions which reduce to `false`, making every postcondition trivially true
+    pub creusot::CONTRADICTORY_REQUIRES,
+    Allow,
+    "preconditions which can never be satisfied"
+}
+
+declare_tool_lint! {
+    /// Detects functions returning a value which none of their postconditions constrain, neither
+    /// directly nor through the final value of a mutable reference
+    pub creusot::UNCONSTRAINED_RESULT,
+    Allow,
+    "postconditions which never mention the result of the function"
+}
+
+declare_tool_lint! {
+    /// Detects trusted program functions which specify nothing about their behavior
+    pub creusot::TRUSTED_WITHOUT_CONTRACT,
+    Allow,
+    "trusted program functions without any contract"
+}
+
+// These lints inspect the translated contract of a function, so they are run by the translation
+// rather than as a `LateLintPass`.
+pub(crate) fn lint_contract(ctx: &mut TranslationCtx, def_id: DefId) {
+    let Some(local_id) = def_id.as_local() else { return };
+    if !matches!(ctx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn) {
+        return;
+    }
+
+    let tcx = ctx.tcx;
+    let hir_id = tcx.hir().local_def_id_to_hir_id(local_id);
+    // The signature is normalized, so that constant conversions and comparisons are folded
+    let sig = ctx.sig(def_id).clone();
+    let contract = &sig.contract;
+
+    for req in &contract.requires {
+        if const_bool(req) == Some(false) {
+            tcx.struct_span_lint_hir(
+                CONTRADICTORY_REQUIRES,
+                hir_id,
+                req.span,
+                "this precondition can never be satisfied",
+                |lint| lint.note("every postcondition of the function holds vacuously"),
+            );
+        }
+    }
+
+    // `#[ensures(false)]` specifies that the function does not return, so has no result
+    if !sig.output.is_unit()
+        && !contract.ensures.is_empty()
+        && !contract
+            .ensures
+            .iter()
+            .any(|ens| mentions_output(ens) || const_bool(ens) == Some(false))
+    {
+        tcx.struct_span_lint_hir(
+            UNCONSTRAINED_RESULT,
+            hir_id,
+            tcx.def_span(def_id),
+            format!("no postcondition of `{}` mentions its result", tcx.item_name(def_id)),
+            |lint| lint,
+        );
+    }
+
+    if util::item_type(tcx, def_id) == ItemType::Program
+        && util::is_trusted(tcx, def_id)
+        && util::get_builtin(tcx, def_id).is_none()
+        && contract.is_empty()
+    {
+        tcx.struct_span_lint_hir(
+            TRUSTED_WITHOUT_CONTRACT,
+            hir_id,
+            tcx.def_span(def_id),
+            format!("trusted function `{}` has no contract", tcx.item_name(def_id)),
+            |lint| lint.help("add `#[requires]` or `#[ensures]` clauses describing its behavior"),
+        );
+    }
+}
+
+// Evaluates the propositional structure of `term`, when it is made of constants only.
+fn const_bool(term: &Term) -> Option<bool> {
+    match &term.kind {
+        TermKind::Lit(Literal::Bool(b)) => Some(*b),
+        TermKind::Unary { op: UnOp::Not, arg } => const_bool(arg).map(|b| !b),
+        TermKind::Binary { op: BinOp::And, lhs, rhs } => match (const_bool(lhs), const_bool(rhs)) {
+            (Some(false), _) | (_, Some(false)) => Some(false),
+            (Some(true), Some(true)) => Some(true),
+            _ => None,
+        },
+        TermKind::Binary { op: BinOp::Or, lhs, rhs } => match (const_bool(lhs), const_bool(rhs)) {
+            (Some(true), _) | (_, Some(true)) => Some(true),
+            (Some(false), Some(false)) => Some(false),
+            _ => None,
+        },
+        TermKind::Impl { lhs, rhs } => match (const_bool(lhs), const_bool(rhs)) {
+            (Some(false), _) | (_, Some(true)) => Some(true),
+            (Some(true), Some(false)) => Some(false),
+            _ => None,
+        },
+        TermKind::Binary { op, lhs, rhs } => {
+            let (l, r) = (const_int(lhs)?, const_int(rhs)?);
+            match op {
+                BinOp::Lt => Some(l < r),
+                BinOp::Le => Some(l <= r),
+                BinOp::Gt => Some(l > r),
+                BinOp::Ge => Some(l >= r),
+                BinOp::Eq => Some(l == r),
+                BinOp::Ne => Some(l != r),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn const_int(term: &Term) -> Option<i128> {
+    match &term.kind {
+        TermKind::Lit(Literal::Integer(i)) | TermKind::Lit(Literal::MachSigned(i, _)) => Some(*i),
+        TermKind::Lit(Literal::MachUnsigned(u, _)) => (*u).try_into().ok(),
+        TermKind::Unary { op: UnOp::Neg, arg } => const_int(arg)?.checked_neg(),
+        TermKind::Binary { op, lhs, rhs } => {
+            let (l, r) = (const_int(lhs)?, const_int(rhs)?);
+            match op {
+                BinOp::Add => l.checked_add(r),
+                BinOp::Sub => l.checked_sub(r),
+                BinOp::Mul => l.checked_mul(r),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+// Whether `term` mentions the result or the final value of a mutable reference
+fn mentions_output(term: &Term) -> bool {
+    struct MentionsOutput(bool);
+
+    impl<'tcx> TermVisitor<'tcx> for MentionsOutput {
+        fn visit_term(&mut self, term: &Term<'tcx>) {
+            match term.kind {
+                TermKind::Var(v) if v == Symbol::intern("result") => self.0 = true,
+                TermKind::Fin { .. } => self.0 = true,
+                _ => super_visit_term(term, self),
+            }
+        }
+    }
+
+    let mut visitor = MentionsOutput(false);
+    visitor.visit_term(term);
+    visitor.0
+}
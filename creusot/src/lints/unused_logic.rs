@@ -0,0 +1,77 @@
+use crate::util;
+use rustc_hir::{
+    def::{DefKind, Res},
+    def_id::DefId,
+    Expr, ExprKind,
+};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use std::collections::HashSet;
+
+declare_tool_lint! {
+    /// Detects logic functions and predicates which are not referenced anywhere in the crate,
+    /// including public ones
+    pub creusot::UNUSED_LOGIC,
+    Allow,
+    "logic functions and predicates which are never used"
+}
+
+#[derive(Default)]
+pub struct UnusedLogic {
+    used: HashSet<DefId>,
+}
+
+impl_lint_pass!(UnusedLogic => [UNUSED_LOGIC]);
+
+impl<'tcx> LateLintPass<'tcx> for UnusedLogic {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) {
+        let used = match e.kind {
+            ExprKind::Path(ref qpath) => match cx.qpath_res(qpath, e.hir_id) {
+                Res::Def(_, def_id) => def_id,
+                _ => return,
+            },
+            ExprKind::MethodCall(..) => {
+                let Some(def_id) = cx.typeck_results().type_dependent_def_id(e.hir_id) else { return };
+                def_id
+            }
+            _ => return,
+        };
+
+        // Recursive calls, including from the function's own contract, don't count as uses
+        if let Some(body) = cx.enclosing_body {
+            let owner = cx.tcx.hir().body_owner_def_id(body).to_def_id();
+            if cx.tcx.typeck_root_def_id(owner) == used {
+                return;
+            }
+        }
+
+        self.used.insert(used);
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for def_id in cx.tcx.hir().body_owners() {
+            let def_id = def_id.to_def_id();
+            if !matches!(cx.tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+                || !(util::is_logic(cx.tcx, def_id) || util::is_predicate(cx.tcx, def_id))
+                || self.used.contains(&def_id)
+            {
+                continue;
+            }
+
+            // Trait items are used through the trait, and laws are used implicitly
+            if cx.tcx.opt_associated_item(def_id).map_or(false, |item| {
+                item.trait_item_def_id.is_some() || cx.tcx.trait_of_item(def_id).is_some()
+            }) || util::is_law(cx.tcx, def_id)
+            {
+                continue;
+            }
+
+            cx.struct_span_lint(
+                UNUSED_LOGIC,
+                cx.tcx.def_span(def_id),
+                format!("`{}` is never used", cx.tcx.item_name(def_id)),
+                |lint| lint,
+            );
+        }
+    }
+}
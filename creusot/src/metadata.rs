@@ -4,23 +4,23 @@ use crate::{
 use creusot_metadata::{
     decoder::{Decodable, MetadataBlob, MetadataDecoder},
     encoder::{Encodable, MetadataEncoder},
+    header::MetadataHeader,
 };
 use indexmap::IndexMap;
 use rustc_hir::def_id::{CrateNum, DefId, LOCAL_CRATE};
 use rustc_macros::{TyDecodable, TyEncodable};
-use rustc_middle::ty::{subst::SubstsRef, TyCtxt, Visibility};
+use rustc_middle::ty::{codec::TyEncoder, subst::SubstsRef, TyCtxt, TyDecoder, Visibility};
 use rustc_span::Symbol;
 use std::{
+    cell::OnceCell,
     collections::HashMap,
     fs::File,
     io::Write,
     path::{Path, PathBuf},
 };
 
-type CloneMetadata<'tcx> = HashMap<DefId, CloneSummary<'tcx>>;
 type ExternSpecs<'tcx> = HashMap<DefId, ExternSpec<'tcx>>;
 
-// TODO: this should lazily load the metadata.
 #[derive(Default)]
 pub struct Metadata<'tcx> {
     crates: HashMap<CrateNum, CrateMetadata<'tcx>>,
@@ -35,7 +35,7 @@ impl<'tcx> Metadata<'tcx> {
     /// Determines whether a DefId has been verified by Creusot or not.
     /// We consider that if we don't have metadata about a crate then it must be unverified
     pub(crate) fn verified(&self, def_id: DefId) -> bool {
-        self.crates.get(&def_id.krate).map_or(false, |meta| meta.dependencies.contains_key(&def_id))
+        self.crates.get(&def_id.krate).map_or(false, |meta| meta.dependencies.contains(def_id))
     }
 
     pub(crate) fn term(&self, def_id: DefId) -> Option<&Term<'tcx>> {
@@ -43,6 +43,11 @@ impl<'tcx> Metadata<'tcx> {
         self.get(def_id.krate)?.term(def_id)
     }
 
+    pub(crate) fn dependencies(&self, def_id: DefId) -> Option<&CloneSummary<'tcx>> {
+        assert!(!def_id.is_local());
+        self.get(def_id.krate)?.dependencies(def_id)
+    }
+
     pub(crate) fn creusot_item(&self, sym: Symbol) -> Option<DefId> {
         for cmeta in &self.crates {
             if cmeta.1.creusot_item(sym).is_some() {
@@ -58,7 +63,9 @@ impl<'tcx> Metadata<'tcx> {
 
     pub(crate) fn load(&mut self, tcx: TyCtxt<'tcx>, overrides: &HashMap<String, String>) {
         for cnum in external_crates(tcx) {
-            let (cmeta, mut ext_specs) = CrateMetadata::load(tcx, overrides, cnum);
+            let Some((cmeta, mut ext_specs)) = CrateMetadata::load(tcx, overrides, cnum) else {
+                continue;
+            };
             self.crates.insert(cnum, cmeta);
 
             for (id, spec) in ext_specs.drain() {
@@ -70,69 +77,92 @@ impl<'tcx> Metadata<'tcx> {
     }
 }
 
+// Entries of the metadata of a crate, which are only decoded the first time they are requested.
+struct LazyTable<T>(HashMap<DefId, (usize, OnceCell<T>)>);
+
+impl<T> LazyTable<T> {
+    fn new(index: Vec<(DefId, usize)>) -> Self {
+        LazyTable(index.into_iter().map(|(id, pos)| (id, (pos, OnceCell::new()))).collect())
+    }
+
+    fn contains(&self, def_id: DefId) -> bool {
+        self.0.contains_key(&def_id)
+    }
+
+    fn get(&self, def_id: DefId, decode: impl FnOnce(usize) -> T) -> Option<&T> {
+        let (pos, entry) = self.0.get(&def_id)?;
+        Some(entry.get_or_init(|| decode(*pos)))
+    }
+}
+
 pub struct CrateMetadata<'tcx> {
-    terms: IndexMap<DefId, Term<'tcx>>,
-    dependencies: CloneMetadata<'tcx>,
+    tcx: TyCtxt<'tcx>,
+    blob: MetadataBlob,
+    terms: LazyTable<Term<'tcx>>,
+    dependencies: LazyTable<CloneSummary<'tcx>>,
     creusot_items: CreusotItems,
-    // extern_specs: HashMap<DefId, PreContract>,
 }
 
 impl<'tcx> CrateMetadata<'tcx> {
-    pub(crate) fn new() -> Self {
-        Self {
-            dependencies: Default::default(),
-            terms: Default::default(),
-            creusot_items: Default::default(),
-            // extern_specs: Default::default(),
-        }
+    pub(crate) fn term(&self, def_id: DefId) -> Option<&Term<'tcx>> {
+        assert!(!def_id.is_local());
+        self.terms.get(def_id, |pos| self.decode_at(pos))
     }
 
-    pub(crate) fn term(&self, def_id: DefId) -> Option<&Term<'tcx>> {
+    pub(crate) fn dependencies(&self, def_id: DefId) -> Option<&CloneSummary<'tcx>> {
         assert!(!def_id.is_local());
-        self.terms.get(&def_id)
+        self.dependencies.get(def_id, |pos| {
+            let summary: Vec<((DefId, SubstsRef<'tcx>), CloneInfo)> = self.decode_at(pos);
+            summary.into_iter().collect()
+        })
     }
 
     pub(crate) fn creusot_item(&self, sym: Symbol) -> Option<DefId> {
         self.creusot_items.symbol_to_id.get(&sym).cloned()
     }
 
+    fn decode_at<T: for<'a> Decodable<MetadataDecoder<'a, 'tcx>>>(&self, pos: usize) -> T {
+        MetadataDecoder::new(self.tcx, &self.blob).with_position(pos, T::decode)
+    }
+
     fn load(
         tcx: TyCtxt<'tcx>,
         overrides: &HashMap<String, String>,
         cnum: CrateNum,
-    ) -> (Self, ExternSpecs<'tcx>) {
-        let mut meta = CrateMetadata::new();
-
+    ) -> Option<(Self, ExternSpecs<'tcx>)> {
         let base_path = creusot_metadata_base_path(tcx, overrides, cnum);
 
         let binary_path = creusot_metadata_binary_path(base_path.clone());
 
-        let mut externs = Default::default();
-        if let Some(metadata) = load_binary_metadata(tcx, cnum, &binary_path) {
-            for (def_id, summary) in metadata.dependencies.into_iter() {
-                meta.dependencies.insert(def_id, summary.into_iter().collect());
-            }
+        let (blob, root) = load_binary_metadata(tcx, cnum, &binary_path)?;
+        let meta = CrateMetadata {
+            tcx,
+            blob,
+            terms: LazyTable::new(root.terms),
+            dependencies: LazyTable::new(root.dependencies),
+            creusot_items: root.creusot_items,
+        };
 
-            for (def_id, summary) in metadata.terms.into_iter() {
-                meta.terms.insert(def_id, summary);
-            }
+        Some((meta, root.extern_specs))
+    }
+}
 
-            meta.creusot_items = metadata.creusot_items;
+// The entry point of the metadata of a crate, containing the positions of its terms and clone
+// summaries so that they can be decoded individually.
+#[derive(TyDecodable, TyEncodable)]
+struct MetadataRoot<'tcx> {
+    dependencies: Vec<(DefId, usize)>,
 
-            externs = metadata.extern_specs;
-        }
+    terms: Vec<(DefId, usize)>,
 
-        (meta, externs)
-    }
+    creusot_items: CreusotItems,
+
+    extern_specs: HashMap<DefId, ExternSpec<'tcx>>,
 }
 
-// We use this type to perform (de)serialization of metadata because for annoying
-// `extern crate` related reasons we cannot use the instance of `TyEncodable` / `TyDecodable`
-// for `IndexMap`. Instead, we flatten it to a association list and then convert that into
-// a proper index map after parsing.
-#[derive(TyDecodable, TyEncodable)]
+// We encode clone summaries as association lists because for annoying `extern crate` related
+// reasons we cannot use the instance of `TyEncodable` / `TyDecodable` for `IndexMap`.
 pub(crate) struct BinaryMetadata<'tcx> {
-    // Flatten the index map into a vector
     dependencies: HashMap<DefId, Vec<((DefId, SubstsRef<'tcx>), CloneInfo)>>,
 
     terms: Vec<(DefId, Term<'tcx>)>,
@@ -180,6 +210,40 @@ impl<'tcx> BinaryMetadata<'tcx> {
             extern_specs: extern_specs.clone(),
         }
     }
+
+    // Encodes every term and clone summary separately, followed by the root indexing them.
+    // Returns the position of the root.
+    fn encode_indexed(self, e: &mut MetadataEncoder<'tcx>) -> usize {
+        let dependencies = self
+            .dependencies
+            .into_iter()
+            .map(|(def_id, summary)| {
+                let pos = e.position();
+                summary.encode(e);
+                (def_id, pos)
+            })
+            .collect();
+
+        let terms = self
+            .terms
+            .into_iter()
+            .map(|(def_id, term)| {
+                let pos = e.position();
+                term.encode(e);
+                (def_id, pos)
+            })
+            .collect();
+
+        let root = e.position();
+        MetadataRoot {
+            dependencies,
+            terms,
+            creusot_items: self.creusot_items,
+            extern_specs: self.extern_specs,
+        }
+        .encode(e);
+        root
+    }
 }
 
 fn export_file(ctx: &TranslationCtx, out: &Option<String>) -> PathBuf {
@@ -208,9 +272,9 @@ fn dump_binary_metadata<'tcx>(
     dep_info: BinaryMetadata<'tcx>,
 ) -> Result<(), std::io::Error> {
     let mut encoder = MetadataEncoder::new(tcx);
-    dep_info.encode(&mut encoder);
+    let root = dep_info.encode_indexed(&mut encoder);
 
-    File::create(path).and_then(|mut file| file.write(&encoder.finish())).map_err(|err| {
+    File::create(path).and_then(|mut file| file.write(&encoder.finish(root))).map_err(|err| {
         warn!("could not encode metadata for crate `{:?}`, error: {:?}", "LOCAL_CRATE", err);
         err
     })?;
@@ -221,19 +285,45 @@ fn load_binary_metadata<'tcx>(
     tcx: TyCtxt<'tcx>,
     cnum: CrateNum,
     path: &Path,
-) -> Option<BinaryMetadata<'tcx>> {
-    let metadata = MetadataBlob::from_file(&path).and_then(|blob| {
-        let mut decoder = MetadataDecoder::new(tcx, &blob);
-        Ok(BinaryMetadata::decode(&mut decoder))
-    });
-
-    match metadata {
-        Ok(b) => Some(b),
+) -> Option<(MetadataBlob, MetadataRoot<'tcx>)> {
+    let blob = match MetadataBlob::from_file(path) {
+        Ok(blob) => blob,
         Err(e) => {
             warn!("could not read metadata for crate `{:?}`: {:?}", tcx.crate_name(cnum), e);
             return None;
         }
+    };
+
+    // Decoding metadata produced by another build of Creusot would crash or return garbage
+    let current = MetadataHeader::current();
+    let header = blob.header();
+    if header.as_ref() != Some(&current) {
+        let crate_name = tcx.crate_name(cnum);
+        let mut err = tcx.sess.struct_err(format!(
+            "the Creusot metadata of crate `{crate_name}` was produced by another version of Creusot"
+        ));
+        match header {
+            Some(header) => err.note(format!(
+                "`{crate_name}` was built by Creusot {} with rustc {}, metadata format {}",
+                header.creusot_version, header.rustc_version, header.format_version
+            )),
+            None => err.note(format!("`{crate_name}` was built by an older version of Creusot")),
+        };
+        err.note(format!(
+            "this is Creusot {} with rustc {}, metadata format {}",
+            current.creusot_version, current.rustc_version, current.format_version
+        ))
+        .note(format!("metadata loaded from `{}`", path.display()))
+        .help(format!(
+            "rebuild `{crate_name}` with this version of Creusot, `cargo clean` removes the outdated metadata"
+        ))
+        .emit();
+        return None;
     }
+
+    let root =
+        MetadataDecoder::new(tcx, &blob).with_position(blob.root_position(), MetadataRoot::decode);
+    Some((blob, root))
 }
 
 fn creusot_metadata_base_path(
@@ -33,6 +33,24 @@ pub struct CreusotArgs {
     /// Specify locations of metadata for external crates. The format is the same as rustc's `--extern` flag.
     #[clap(long = "creusot-extern", value_parser= parse_key_val::<String, String>, required=false)]
     extern_paths: Vec<(String, String)>,
+    /// Emit a goal checking that the preconditions of each function are satisfiable.
+    #[clap(long)]
+    check_requires_sat: bool,
+    /// Print a per-module summary of verified, trusted and unspecified functions.
+    #[clap(long)]
+    coverage: bool,
+    /// Print the trusted items, extern specs, builtins and laws which the items matching
+    /// `--focus-on` (or the whole crate) rely on.
+    #[clap(long)]
+    audit: bool,
+    /// Require every program function of the crate to terminate, as if it was marked
+    /// `#[terminates]`.
+    #[clap(long)]
+    check_termination: bool,
+    /// Print which program functions are proved panic-free and under which condition the others
+    /// may panic.
+    #[clap(long)]
+    panic_report: bool,
 }
 
 /// Parse a single key-value pair
@@ -72,6 +90,11 @@ pub struct Options {
     pub(crate) in_cargo: bool,
     pub(crate) span_mode: SpanMode,
     pub(crate) match_str: Option<String>,
+    pub(crate) check_requires_sat: bool,
+    pub(crate) coverage: bool,
+    pub(crate) audit: bool,
+    pub(crate) check_termination: bool,
+    pub(crate) panic_report: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -103,6 +126,11 @@ impl Options {
             in_cargo: cargo_creusot,
             span_mode: args.span_mode,
             match_str: args.focus_on,
+            check_requires_sat: args.check_requires_sat,
+            coverage: args.coverage,
+            audit: args.audit,
+            check_termination: args.check_termination,
+            panic_report: args.panic_report,
         }
     }
 
@@ -0,0 +1,101 @@
+// Panic freedom: functions without a `#[may_panic]` clause are proved never to panic, while the
+// others may only panic when their condition holds.
+
+use crate::{
+    ctx::{ItemType, TranslationCtx},
+    translation::pearlite::{Literal, TermKind},
+    util,
+};
+use rustc_hir::def::DefKind;
+use rustc_span::def_id::LOCAL_CRATE;
+use std::{collections::BTreeMap, fmt};
+
+enum PanicStatus {
+    PanicFree,
+    MayPanic,
+    MayPanicWhen(String),
+    Trusted,
+    Unsupported,
+}
+
+impl fmt::Display for PanicStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PanicStatus::PanicFree => write!(f, "panic-free"),
+            PanicStatus::MayPanic => write!(f, "may panic"),
+            PanicStatus::MayPanicWhen(cond) => write!(f, "may panic when `{cond}`"),
+            PanicStatus::Trusted => write!(f, "trusted"),
+            PanicStatus::Unsupported => write!(f, "unsupported"),
+        }
+    }
+}
+
+pub(crate) struct PanicReport {
+    crate_name: String,
+    functions: BTreeMap<String, PanicStatus>,
+}
+
+// Calls through a trait are not checked against the panic condition of the implementation
+pub(crate) fn check_may_panic(ctx: &mut TranslationCtx) {
+    let tcx = ctx.tcx;
+    for local_id in tcx.hir().body_owners() {
+        let def_id = local_id.to_def_id();
+        if tcx.def_kind(def_id) != DefKind::AssocFn || !util::should_translate(tcx, def_id) {
+            continue;
+        }
+
+        let in_trait = tcx.trait_of_item(def_id).is_some()
+            || tcx.impl_of_method(def_id).and_then(|i| tcx.trait_id_of_impl(i)).is_some();
+        if in_trait && ctx.sig(def_id).contract.may_panic.is_some() {
+            ctx.error(tcx.def_span(def_id), "`may_panic` is not supported on trait methods");
+        }
+    }
+}
+
+pub(crate) fn panic_report(ctx: &mut TranslationCtx) -> PanicReport {
+    let tcx = ctx.tcx;
+    let mut report = PanicReport {
+        crate_name: tcx.crate_name(LOCAL_CRATE).to_string(),
+        functions: BTreeMap::new(),
+    };
+
+    for local_id in tcx.hir().body_owners() {
+        let def_id = local_id.to_def_id();
+        if !matches!(tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+            || util::is_extern_spec(tcx, def_id)
+            || !matches!(util::item_type(tcx, def_id), ItemType::Program)
+        {
+            continue;
+        }
+
+        let status = if !util::should_translate(tcx, def_id) || ctx.item(def_id).is_none() {
+            PanicStatus::Unsupported
+        } else if util::is_trusted(tcx, def_id) {
+            PanicStatus::Trusted
+        } else {
+            match &ctx.sig(def_id).contract.may_panic {
+                None => PanicStatus::PanicFree,
+                Some(cond) if matches!(cond.kind, TermKind::Lit(Literal::Bool(true))) => {
+                    PanicStatus::MayPanic
+                }
+                Some(cond) => match tcx.sess.source_map().span_to_snippet(cond.span) {
+                    Ok(snippet) => PanicStatus::MayPanicWhen(snippet),
+                    Err(_) => PanicStatus::MayPanic,
+                },
+            }
+        };
+        report.functions.insert(tcx.def_path_str(def_id), status);
+    }
+
+    report
+}
+
+impl fmt::Display for PanicReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Panic freedom of `{}`, assuming its proofs succeed", self.crate_name)?;
+        for (name, status) in &self.functions {
+            writeln!(f, "{name}: {status}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,252 @@
+// Checks the requirements of `#[terminates]`: terminating functions may only call terminating
+// functions, and their loops and recursive calls must be justified by variants.
+
+use crate::{
+    ctx::{ItemType, TranslationCtx},
+    translation::fmir,
+    util,
+};
+use indexmap::IndexSet;
+use petgraph::{algo::tarjan_scc, graphmap::DiGraphMap};
+use rustc_errors::DiagnosticId;
+use rustc_hir::{def::DefKind, def_id::DefId};
+use rustc_middle::{
+    mir::{BasicBlock, Body, MirPass, TerminatorKind},
+    ty::{subst::SubstsRef, Instance, InstanceDef, ParamEnv, TyCtxt, TyKind, WithOptConstParam},
+};
+use rustc_mir_transform::{cleanup_post_borrowck::CleanupPostBorrowck, simplify::SimplifyCfg};
+use rustc_span::Span;
+use std::collections::HashSet;
+
+// Whether `def_id` is required to terminate. Closures inherit the requirement of their parent.
+pub(crate) fn must_terminate(ctx: &TranslationCtx, def_id: DefId) -> bool {
+    ctx.opts.check_termination || util::is_terminates(ctx.tcx, ctx.typeck_root_def_id(def_id))
+}
+
+// Functions of other crates which are not verified by Creusot, such as those of `std`, are
+// assumed to terminate: they are listed in the trust base
+pub(crate) fn assumed_terminating(ctx: &TranslationCtx, def_id: DefId) -> bool {
+    !def_id.is_local() && !ctx.externs.verified(def_id)
+}
+
+fn callee_terminates(ctx: &TranslationCtx, def_id: DefId) -> bool {
+    if def_id.is_local() {
+        must_terminate(ctx, def_id)
+    } else {
+        util::is_terminates(ctx.tcx, def_id)
+    }
+}
+
+pub(crate) fn is_program(ctx: &TranslationCtx, def_id: DefId) -> bool {
+    ctx.def_kind(def_id) != DefKind::AnonConst
+        && matches!(util::item_type(ctx.tcx, def_id), ItemType::Program | ItemType::Closure)
+}
+
+pub(crate) fn check_termination(ctx: &mut TranslationCtx) {
+    let tcx = ctx.tcx;
+    let functions: IndexSet<DefId> = tcx
+        .hir()
+        .body_owners()
+        .map(|id| id.to_def_id())
+        .filter(|id| util::should_translate(tcx, *id) && is_program(ctx, *id))
+        .filter(|id| must_terminate(ctx, *id))
+        .collect();
+
+    let mut graph = DiGraphMap::<DefId, ()>::new();
+    for &def_id in &functions {
+        graph.add_node(def_id);
+        if util::is_trusted(tcx, def_id) || !util::has_body(ctx, def_id) {
+            continue;
+        }
+
+        let param_env = ctx.param_env(def_id);
+        for (callee, subst, span) in calls(ctx, def_id) {
+            if !is_program(ctx, callee) {
+                continue;
+            }
+
+            let Some(callee) = resolve_callee(tcx, param_env, callee, subst) else {
+                tcx.sess
+                    .struct_span_err_with_code(
+                        span,
+                        format!(
+                            "`{}` must terminate but calls `{}`, which cannot be resolved to a known function",
+                            tcx.def_path_str(def_id),
+                            tcx.def_path_str(callee)
+                        ),
+                        DiagnosticId::Error(String::from("creusot")),
+                    )
+                    .emit();
+                continue;
+            };
+
+            if !is_program(ctx, callee) || assumed_terminating(ctx, callee) {
+                continue;
+            }
+
+            if !callee_terminates(ctx, callee) {
+                let mut err = tcx.sess.struct_span_err_with_code(
+                    span,
+                    format!(
+                        "`{}` must terminate but calls `{}`, which may not terminate",
+                        tcx.def_path_str(def_id),
+                        tcx.def_path_str(callee)
+                    ),
+                    DiagnosticId::Error(String::from("creusot")),
+                );
+                if callee.is_local() {
+                    err.span_note(tcx.def_span(callee), "consider marking it `#[terminates]`");
+                }
+                err.emit();
+                continue;
+            }
+            if callee.is_local() {
+                graph.add_edge(def_id, callee, ());
+            }
+        }
+    }
+
+    let mut in_cycle = HashSet::new();
+    for scc in tarjan_scc(&graph) {
+        let recursive = scc.len() > 1 || graph.contains_edge(scc[0], scc[0]);
+        if !recursive {
+            continue;
+        }
+
+        // Functions are translated to separate modules, so a variant cannot justify calls to
+        // another function of the cycle
+        if scc.len() > 1 {
+            let cycle: Vec<_> =
+                scc.iter().map(|id| format!("`{}`", tcx.def_path_str(*id))).collect();
+            for &def_id in &scc {
+                tcx.sess
+                    .struct_span_err_with_code(
+                        tcx.def_span(def_id),
+                        format!(
+                            "mutually recursive function `{}` cannot be proved terminating",
+                            tcx.def_path_str(def_id)
+                        ),
+                        DiagnosticId::Error(String::from("creusot")),
+                    )
+                    .note(format!("it is part of the recursive cycle {}", cycle.join(", ")))
+                    .emit();
+            }
+            in_cycle.extend(scc);
+            continue;
+        }
+
+        let def_id = scc[0];
+        if ctx.sig(def_id).contract.variant.is_none() {
+            tcx.sess
+                .struct_span_err_with_code(
+                    tcx.def_span(def_id),
+                    format!(
+                        "recursive function `{}` must have a `#[variant]` to be proved terminating",
+                        tcx.def_path_str(def_id)
+                    ),
+                    DiagnosticId::Error(String::from("creusot")),
+                )
+                .emit();
+        }
+    }
+
+    // Translating the body of a function of a cycle would give up on the cycle
+    for &def_id in &functions {
+        if util::is_trusted(tcx, def_id)
+            || !util::has_body(ctx, def_id)
+            || in_cycle.contains(&def_id)
+        {
+            continue;
+        }
+        check_loops(ctx, def_id);
+    }
+}
+
+// The calls made by the MIR of `def_id`, which unlike its fmir is built without translating
+// the callees
+pub(crate) fn calls<'tcx>(
+    ctx: &TranslationCtx<'tcx>,
+    def_id: DefId,
+) -> Vec<(DefId, SubstsRef<'tcx>, Span)> {
+    let (mir, _) = ctx.mir_promoted(WithOptConstParam::unknown(def_id.expect_local()));
+    let mir = mir.borrow();
+    mir.basic_blocks
+        .iter()
+        .filter_map(|block| match &block.terminator().kind {
+            TerminatorKind::Call { func, .. } => {
+                let (callee, subst) = func.const_fn_def()?;
+                Some((callee, subst, block.terminator().source_info.span))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+// The function run by a call, or `None` when it is only known through a trait bound or a
+// trait object
+pub(crate) fn resolve_callee<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ParamEnv<'tcx>,
+    def_id: DefId,
+    subst: SubstsRef<'tcx>,
+) -> Option<DefId> {
+    if tcx.trait_of_item(def_id).is_none() {
+        return Some(def_id);
+    }
+
+    match Instance::resolve(tcx, param_env, def_id, subst).ok()??.def {
+        InstanceDef::Item(item) => Some(item.did),
+        // A closure implementing `Fn` called through `FnOnce`
+        InstanceDef::ClosureOnceShim { .. } => match subst.type_at(0).kind() {
+            TyKind::Closure(id, _) => Some(*id),
+            _ => None,
+        },
+        InstanceDef::Virtual(..) | InstanceDef::FnPtrShim(..) => None,
+        // The remaining shims are generated by the compiler
+        def => Some(def.def_id()),
+    }
+}
+
+// Every loop of a terminating function must have a variant
+fn check_loops(ctx: &mut TranslationCtx, def_id: DefId) {
+    // The MIR from which the fmir body was translated, so that their basic blocks agree
+    let (mir, _) = ctx.mir_promoted(WithOptConstParam::unknown(def_id.expect_local()));
+    let mut mir = mir.borrow().clone();
+    CleanupPostBorrowck.run_pass(ctx.tcx, &mut mir);
+    SimplifyCfg::new("verify").run_pass(ctx.tcx, &mut mir);
+
+    let body = ctx.fmir_body(def_id).unwrap();
+    let missing: Vec<_> = loop_headers(&mir)
+        .into_iter()
+        .filter(|header| {
+            !body.blocks[header]
+                .stmts
+                .iter()
+                .any(|stmt| matches!(stmt, fmir::Statement::Variant(_)))
+        })
+        .collect();
+
+    for header in missing {
+        ctx.error(
+            mir[header].terminator().source_info.span,
+            &format!(
+                "loop in `{}` must have a `#[variant]` to be proved terminating",
+                ctx.def_path_str(def_id)
+            ),
+        );
+    }
+}
+
+// Blocks which dominate one of their predecessors
+fn loop_headers(body: &Body) -> Vec<BasicBlock> {
+    let dominators = body.basic_blocks.dominators();
+    body.basic_blocks
+        .indices()
+        .filter(|bb| !body[*bb].is_cleanup)
+        .filter(|bb| {
+            body.basic_blocks.predecessors()[*bb]
+                .iter()
+                .any(|pred| dominators.dominates(*bb, *pred))
+        })
+        .collect()
+}
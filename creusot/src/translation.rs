@@ -8,12 +8,15 @@ pub(crate) mod specification;
 pub(crate) mod traits;
 
 use crate::{
-    ctx,
+    audit, coverage, ctx,
     ctx::load_extern_specs,
     error::CrErr,
-    metadata,
+    lints, metadata,
     options::OutputFile,
-    validate::{validate_impls, validate_traits},
+    panics, termination,
+    validate::{
+        validate_impls, validate_lemmas, validate_opacity, validate_proof_attrs, validate_traits,
+    },
 };
 use ctx::TranslationCtx;
 pub(crate) use function::LocalIdent;
@@ -43,6 +46,9 @@ pub(crate) fn before_analysis(ctx: &mut TranslationCtx) -> Result<(), Box<dyn Er
     // Check that all trait laws are well-formed
     validate_traits(ctx);
     validate_impls(ctx);
+    validate_lemmas(ctx);
+    validate_opacity(ctx);
+    validate_proof_attrs(ctx);
 
     debug!("before_analysis: {:?}", start.elapsed());
     Ok(())
@@ -55,6 +61,13 @@ pub(crate) fn after_analysis(mut ctx: TranslationCtx) -> Result<(), Box<dyn Erro
         ctx.translate_trait(*tr);
     }
 
+    // Before translation, which gives up on recursive cycles of program functions
+    let errors = ctx.tcx.sess.err_count();
+    termination::check_termination(&mut ctx);
+    if ctx.tcx.sess.err_count() > errors {
+        return Err(Box::new(CrErr));
+    }
+
     let start = Instant::now();
     for def_id in ctx.tcx.hir().body_owners() {
         let def_id = def_id.to_def_id();
@@ -70,8 +83,11 @@ pub(crate) fn after_analysis(mut ctx: TranslationCtx) -> Result<(), Box<dyn Erro
 
         info!("Translating body {:?}", def_id);
         ctx.translate(def_id);
+        lints::lint_contract(&mut ctx, def_id);
     }
 
+    panics::check_may_panic(&mut ctx);
+
     for impls in ctx.tcx.all_local_trait_impls(()).values() {
         for impl_id in impls {
             ctx.translate(impl_id.to_def_id());
@@ -89,6 +105,18 @@ pub(crate) fn after_analysis(mut ctx: TranslationCtx) -> Result<(), Box<dyn Erro
         metadata::dump_exports(&ctx, &ctx.opts.metadata_path);
     }
 
+    if ctx.opts.coverage && ctx.should_compile() {
+        eprint!("{}", coverage::coverage(&mut ctx));
+    }
+
+    if ctx.opts.audit && ctx.should_compile() {
+        eprint!("{}", audit::trust_base(&mut ctx));
+    }
+
+    if ctx.opts.panic_report && ctx.should_compile() {
+        eprint!("{}", panics::panic_report(&mut ctx));
+    }
+
     if ctx.should_compile() {
         use std::fs::File;
         let mut out: Box<dyn Write> = match ctx.opts.output_file {
@@ -70,21 +70,12 @@ pub(crate) fn extract_extern_specs_from_item<'tcx>(
     let mut inner_subst = InternalSubsts::identity_for_item(ctx.tcx, id).to_vec();
     let outer_subst = InternalSubsts::identity_for_item(ctx.tcx, def_id.to_def_id());
 
-    let extra_parameters = inner_subst.len() - outer_subst.len();
+    // The parameters of the specification are those of the impl or trait (including `Self_`)
+    // followed by those of the method, except that rustc lists lifetimes first since it is a
+    // function. Reorder the original parameters the same way.
+    inner_subst.sort_by_key(|arg| !matches!(arg.unpack(), GenericArgKind::Lifetime(_)));
 
-    // Move Self_ to the front of the list like rustc does for real trait impls (not expressible in surface rust).
-    // This only matters when we also have lifetime parameters.
-    let self_pos = outer_subst.iter().position(|e| {
-        if
-        let GenericArgKind::Type(t) = e.unpack() &&
-        let TyKind::Param(t) = t.kind() &&
-        t.name.as_str().starts_with("Self") { true } else { false }
-    });
-
-    if let Some(ix) = self_pos {
-        let self_ = inner_subst.remove(ix + extra_parameters);
-        inner_subst.insert(0, self_);
-    };
+    let extra_parameters = inner_subst.len() - outer_subst.len();
 
     let mut subst = Vec::new();
     let mut errors = Vec::new();
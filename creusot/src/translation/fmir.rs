@@ -16,7 +16,11 @@ pub enum Statement<'tcx> {
     // The reason I have not done this yet is that it would require transforming a `Place` to a `Term`.
     Resolve(DefId, SubstsRef<'tcx>, Place<'tcx>),
     Assertion(Term<'tcx>),
+    Assume(Term<'tcx>),
     Invariant(Symbol, Term<'tcx>),
+    Variant(Term<'tcx>),
+    // Records the value of a term for later uses of `at!` or `old!`
+    Snapshot(LocalIdent, Term<'tcx>),
 }
 
 // Re-organize this completely
@@ -78,6 +82,45 @@ pub struct Body<'tcx> {
     pub(crate) blocks: IndexMap<BasicBlock, Block<'tcx>>,
 }
 
+impl<'tcx> Body<'tcx> {
+    // The functions called by this body, along with the span of each call
+    pub(crate) fn calls(&self) -> Vec<(DefId, SubstsRef<'tcx>, Span)> {
+        let mut calls = Vec::new();
+        for block in self.blocks.values() {
+            for stmt in &block.stmts {
+                if let Statement::Assignment(_, RValue::Expr(expr)) = stmt {
+                    expr.calls(None, &mut calls);
+                }
+            }
+            if let Terminator::Switch(expr, _) = &block.terminator {
+                expr.calls(None, &mut calls);
+            }
+        }
+        calls
+    }
+}
+
+impl<'tcx> Expr<'tcx> {
+    fn calls(&self, span: Option<Span>, calls: &mut Vec<(DefId, SubstsRef<'tcx>, Span)>) {
+        match self {
+            Expr::Call(id, subst, args) => {
+                calls.push((*id, *subst, span.unwrap_or(rustc_span::DUMMY_SP)));
+                args.iter().for_each(|a| a.calls(span, calls));
+            }
+            Expr::Span(sp, e) => e.calls(Some(*sp), calls),
+            Expr::BinOp(_, _, l, r) | Expr::Repeat(l, r) => {
+                l.calls(span, calls);
+                r.calls(span, calls);
+            }
+            Expr::UnaryOp(_, e) | Expr::Cast(e, _, _) | Expr::Len(e) => e.calls(span, calls),
+            Expr::Constructor(_, _, args) | Expr::Tuple(args) | Expr::Array(args) => {
+                args.iter().for_each(|a| a.calls(span, calls))
+            }
+            Expr::Place(_) | Expr::Move(_) | Expr::Copy(_) | Expr::Constant(_) => {}
+        }
+    }
+}
+
 pub(crate) fn resolve_predicate_of2<'tcx>(
     ctx: &mut TranslationCtx<'tcx>,
     param_env: ParamEnv<'tcx>,
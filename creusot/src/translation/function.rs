@@ -6,7 +6,9 @@ use crate::{
     backend::place,
     ctx::*,
     fmir::{self, Expr},
-    gather_spec_closures::corrected_invariant_names_and_locations,
+    gather_spec_closures::{
+        corrected_invariant_names_and_locations, GhostBlock, LoopSpec, Snapshots, SpecClosures,
+    },
     resolve::EagerResolver,
     rustc_extensions::renumber,
     translation::{
@@ -23,7 +25,7 @@ use rustc_infer::infer::TyCtxtInferExt;
 use rustc_middle::{
     mir::{
         traversal::reverse_postorder, BasicBlock, Body, Local, Location, MirPass, Operand, Place,
-        VarDebugInfo,
+        VarDebugInfo, START_BLOCK,
     },
     ty::{
         subst::{GenericArg, SubstsRef},
@@ -82,10 +84,14 @@ pub struct BodyTranslator<'body, 'tcx> {
     // Fresh BlockId
     fresh_id: usize,
 
-    invariants: IndexMap<BasicBlock, Vec<(Symbol, Term<'tcx>)>>,
+    invariants: IndexMap<BasicBlock, Vec<LoopSpec<'tcx>>>,
 
     assertions: IndexMap<DefId, Term<'tcx>>,
 
+    ghosts: IndexMap<DefId, GhostBlock<'tcx>>,
+
+    snapshots: Snapshots<'tcx>,
+
     borrows: Rc<BorrowSet<'tcx>>,
 }
 
@@ -97,7 +103,8 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
         // names: &'body mut CloneMap<'tcx>,
         def_id: DefId,
     ) -> Self {
-        let (invariants, assertions) = corrected_invariant_names_and_locations(ctx, def_id, &body);
+        let SpecClosures { invariants, assertions, ghosts, snapshots } =
+            corrected_invariant_names_and_locations(ctx, def_id, body);
         let mut erased_locals = BitSet::new_empty(body.local_decls.len());
 
         body.local_decls.iter_enumerated().for_each(|(local, decl)| {
@@ -132,6 +139,8 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
             fresh_id: body.basic_blocks.len(),
             invariants,
             assertions,
+            ghosts,
+            snapshots,
             borrows,
         }
     }
@@ -143,7 +152,9 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
         let vars = self.translate_vars();
 
         assert!(self.assertions.is_empty(), "unused assertions");
+        assert!(self.ghosts.is_empty(), "unused ghost blocks");
         assert!(self.invariants.is_empty(), "unused invariants");
+        assert!(self.snapshots.at_closure.is_empty(), "unused snapshots");
 
         fmir::Body { locals: vars, arg_count, blocks: self.past_blocks }
     }
@@ -155,8 +166,18 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
                 continue;
             }
 
-            for (name, body) in self.invariants.remove(&bb).unwrap_or_else(Vec::new) {
-                self.emit_statement(fmir::Statement::Invariant(name, body));
+            if bb == START_BLOCK {
+                for (id, term) in std::mem::take(&mut self.snapshots.at_entry) {
+                    self.emit_statement(fmir::Statement::Snapshot(id, term));
+                }
+            }
+
+            for spec in self.invariants.remove(&bb).unwrap_or_default() {
+                let stmt = match spec {
+                    LoopSpec::Invariant(name, body) => fmir::Statement::Invariant(name, body),
+                    LoopSpec::Variant(body) => fmir::Statement::Variant(body),
+                };
+                self.emit_statement(stmt);
             }
 
             self.freeze_locals_between_blocks(bb);
@@ -192,6 +213,7 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
             vars.push((ident, decl.source_info.span, decl.ty))
         }
 
+        vars.extend(std::mem::take(&mut self.snapshots.locals));
         vars
     }
 
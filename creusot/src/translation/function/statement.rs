@@ -144,6 +144,12 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
                         Expr::Constructor(variant, subst, fields)
                     }
                     Closure(def_id, subst) => {
+                        for (id, term) in
+                            self.snapshots.at_closure.remove(def_id).unwrap_or_default()
+                        {
+                            self.emit_statement(fmir::Statement::Snapshot(id, term));
+                        }
+
                         if util::is_invariant(self.tcx, *def_id) {
                             return;
                         } else if util::is_assertion(self.tcx, *def_id) {
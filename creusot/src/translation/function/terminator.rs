@@ -3,10 +3,10 @@ use crate::{
     ctx::TranslationCtx,
     translation::{
         fmir::{self, Branches, Expr, RValue, Terminator},
-        pearlite::{Term, TermKind, UnOp},
+        pearlite::{normalize, BinOp, Term, TermKind, UnOp},
         traits,
     },
-    util::is_ghost_closure,
+    util::{is_ghost_closure, is_panic_fn},
 };
 use itertools::Itertools;
 use rustc_hir::def_id::DefId;
@@ -16,19 +16,23 @@ use rustc_infer::{
 };
 use rustc_middle::{
     mir::{
-        self, BasicBlock, BasicBlockData, Location, Operand, Place, Rvalue, SourceInfo,
+        self, BasicBlock, BasicBlockData, Field, Location, Operand, Place, Rvalue, SourceInfo,
         StatementKind, SwitchTargets, TerminatorKind, TerminatorKind::*,
     },
     ty::{
         self,
         subst::{GenericArgKind, SubstsRef},
-        ParamEnv, Predicate, Ty, TyKind,
+        EarlyBinder, ParamEnv, Predicate, Ty, TyKind,
     },
 };
-use rustc_span::Span;
+use rustc_span::{sym, Span};
+use rustc_target::abi::VariantIdx;
 use rustc_trait_selection::traits::{error_reporting::TypeErrCtxtExt, TraitEngineExt};
 use std::collections::HashMap;
 
+// The index of the `Err` variant of `Result`
+const RESULT_ERR: VariantIdx = VariantIdx::from_u32(1);
+
 // Translate the terminator of a basic block.
 // There isn't much that's special about this. The only subtlety is in how
 // we translate switchInt. We rewrite it into a primitive constructor match
@@ -66,6 +70,12 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
             Call { func, args, destination, target, .. } => {
                 if target.is_none() {
                     // If we have no target block after the call, then we cannot move past it.
+                    // Only panics are allowed by `#[may_panic]`, other diverging calls such as
+                    // `exit` must be unreachable.
+                    let panics = func.constant().and_then(|_| func_defid(func));
+                    if panics.map_or(false, |(def_id, _)| is_panic_fn(self.tcx, def_id)) {
+                        self.emit_panic(span.source_callsite());
+                    }
                     self.emit_terminator(Terminator::Abort);
                     return;
                 }
@@ -75,10 +85,13 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
                 if let Some(param) = subst.get(0) &&
                     let GenericArgKind::Type(ty) = param.unpack() &&
                     let Some(def_id) = is_ghost_closure(self.tcx, ty) {
-                    let assertion = self.assertions.remove(&def_id).unwrap();
+                    let ghost = self.ghosts.remove(&def_id).unwrap();
                     let (loc, bb) = (destination, target.unwrap());
 
-                    self.emit_ghost_assign(*loc, assertion);
+                    for stmt in ghost.stmts {
+                        self.emit_statement(stmt);
+                    }
+                    self.emit_ghost_assign(*loc, ghost.value);
                     self.emit_terminator(Terminator::Goto(bb));
                     return;
                 }
@@ -96,28 +109,24 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
                     infcx.err_ctxt().report_fulfillment_errors(&errs);
                 }
 
-                let mut func_args: Vec<_> =
-                    args.iter().map(|arg| self.translate_operand(arg)).collect();
-
-                if func_args.is_empty() {
-                    // We use tuple as a dummy argument for 0-ary functions
-                    func_args.push(Expr::Tuple(vec![]))
-                }
                 let call_exp = if self.is_box_new(fun_def_id) {
-                    assert_eq!(func_args.len(), 1);
-
-                    func_args.remove(0)
+                    assert_eq!(args.len(), 1);
+
+                    self.translate_operand(&args[0])
+                } else if let Some((from_subst, err)) =
+                    self.residual_conversion(fun_def_id, subst, args)
+                {
+                    // `Err(From::from(e))`
+                    let TyKind::Adt(res, res_subst) = subst.type_at(0).kind() else { unreachable!() };
+                    self.ctx.translate(res.did());
+                    let from = self.convert_from_method();
+                    let conv = self.translate_call(from, from_subst, &[err], span);
+                    Expr::Constructor(res.variant(RESULT_ERR).def_id, res_subst, vec![conv])
+                } else if let Some(from_subst) = self.conversion_of_into(fun_def_id, subst) {
+                    let from = self.convert_from_method();
+                    self.translate_call(from, from_subst, args, span)
                 } else {
-                    let (fun_def_id, subst) =
-                        resolve_function(self.ctx, self.param_env(), fun_def_id, subst, span);
-                    let subst = self
-                        .ctx
-                        .try_normalize_erasing_regions(self.param_env(), subst)
-                        .unwrap_or(subst);
-
-                    let exp = Expr::Call(fun_def_id, subst, func_args);
-                    let span = span.source_callsite();
-                    Expr::Span(span, Box::new(exp))
+                    self.translate_call(fun_def_id, subst, args, span)
                 };
 
                 let (loc, bb) = (destination, target.unwrap());
@@ -146,7 +155,23 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
                         kind: TermKind::Unary { op: UnOp::Not, arg: Box::new(ass) },
                     };
                 }
-                self.emit_statement(fmir::Statement::Assertion(ass));
+                match self.may_panic() {
+                    // A failing assertion panics, which is allowed when the panic condition holds
+                    Some(cond) => {
+                        let allowed = Term {
+                            ty: ass.ty,
+                            span: ass.span,
+                            kind: TermKind::Binary {
+                                op: BinOp::Or,
+                                lhs: Box::new(cond),
+                                rhs: Box::new(ass.clone()),
+                            },
+                        };
+                        self.emit_statement(fmir::Statement::Assertion(allowed));
+                        self.emit_statement(fmir::Statement::Assume(ass));
+                    }
+                    None => self.emit_statement(fmir::Statement::Assertion(ass)),
+                }
                 self.emit_terminator(mk_goto(*target))
             }
 
@@ -163,9 +188,142 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
         }
     }
 
+    fn translate_call(
+        &mut self,
+        fun_def_id: DefId,
+        subst: SubstsRef<'tcx>,
+        args: &[Operand<'tcx>],
+        span: Span,
+    ) -> Expr<'tcx> {
+        let mut func_args: Vec<_> = args.iter().map(|arg| self.translate_operand(arg)).collect();
+
+        if func_args.is_empty() {
+            // We use tuple as a dummy argument for 0-ary functions
+            func_args.push(Expr::Tuple(vec![]))
+        }
+
+        let (fun_def_id, subst) =
+            resolve_function(self.ctx, self.param_env(), fun_def_id, subst, span);
+        let subst =
+            self.ctx.try_normalize_erasing_regions(self.param_env(), subst).unwrap_or(subst);
+
+        self.check_callee_panic(fun_def_id, subst, args, span);
+
+        let exp = Expr::Call(fun_def_id, subst, func_args);
+        let span = span.source_callsite();
+        Expr::Span(span, Box::new(exp))
+    }
+
+    // Conversions through the blanket impls of `Into` and of `FromResidual` for `Result` are
+    // translated as calls to `From::from`, so that they use the contract of the `From` impl.
+    fn convert_from_method(&self) -> DefId {
+        let from = self.tcx.get_diagnostic_item(sym::From).unwrap();
+        self.tcx.associated_items(from).filter_by_name_unhygienic(sym::from).next().unwrap().def_id
+    }
+
+    // `T::into` is `U::from` when it comes from `impl<T, U: From<T>> Into<U> for T`
+    fn conversion_of_into(&self, def_id: DefId, subst: SubstsRef<'tcx>) -> Option<SubstsRef<'tcx>> {
+        if self.tcx.trait_of_item(def_id) != self.tcx.get_diagnostic_item(sym::Into) {
+            return None;
+        }
+
+        let (method, _) =
+            traits::resolve_assoc_item_opt(self.tcx, self.param_env(), def_id, subst)?;
+        let impl_id = self.tcx.impl_of_method(method)?;
+        let self_ty = self.tcx.type_of(impl_id).subst_identity();
+        if impl_id.is_local() || !matches!(self_ty.kind(), TyKind::Param(_)) {
+            return None;
+        }
+
+        Some(self.tcx.mk_substs(&[subst[1], subst[0]]))
+    }
+
+    // `Result<T, F>::from_residual(r)` with `r : Result<Infallible, E>` is `Err(F::from(e))` when
+    // `r` is `Err(e)`, which holds at the `?` operators calling it
+    fn residual_conversion(
+        &self,
+        def_id: DefId,
+        subst: SubstsRef<'tcx>,
+        args: &[Operand<'tcx>],
+    ) -> Option<(SubstsRef<'tcx>, Operand<'tcx>)> {
+        if Some(def_id) != self.tcx.lang_items().from_residual_fn() {
+            return None;
+        }
+
+        let (TyKind::Adt(res, res_subst), TyKind::Adt(residual, residual_subst)) =
+            (subst.type_at(0).kind(), subst.type_at(1).kind()) else { return None };
+        if !self.tcx.is_diagnostic_item(sym::Result, res.did()) || res != residual {
+            return None;
+        }
+
+        let place = args[0].place()?;
+        let err = self.tcx.mk_place_downcast(place, *residual, RESULT_ERR);
+        let err = self.tcx.mk_place_field(err, Field::from_u32(0), residual_subst.type_at(1));
+        Some((self.tcx.mk_substs(&[res_subst[1], residual_subst[1]]), Operand::Move(err)))
+    }
+
     fn is_box_new(&self, def_id: DefId) -> bool {
         self.tcx.def_path_str(def_id) == "std::boxed::Box::<T>::new"
     }
+
+    // The condition under which the function being translated is allowed to panic
+    fn may_panic(&mut self) -> Option<Term<'tcx>> {
+        self.ctx.sig(self.def_id).contract.may_panic.clone()
+    }
+
+    // Reaching a panic is only possible in `#[may_panic]` functions, when their condition holds
+    fn emit_panic(&mut self, span: Span) {
+        if let Some(cond) = self.may_panic() {
+            let absurd = Term { span, ..Term::mk_false(self.tcx) };
+            self.emit_statement(fmir::Statement::Assertion(Term { span, ..cond }));
+            self.emit_statement(fmir::Statement::Assume(absurd));
+        }
+    }
+
+    // A call to a `#[may_panic]` function must either exclude its panic condition, or have it
+    // covered by the panic condition of the caller
+    fn check_callee_panic(
+        &mut self,
+        callee: DefId,
+        subst: SubstsRef<'tcx>,
+        args: &[Operand<'tcx>],
+        span: Span,
+    ) {
+        let Some(cond) = self.ctx.sig(callee).contract.may_panic.clone() else { return };
+        let inputs = self.ctx.sig(callee).inputs.clone();
+
+        let mut cond = EarlyBinder(cond).subst(self.tcx, subst);
+        normalize(self.tcx, self.param_env(), &mut cond);
+
+        let mut terms = HashMap::new();
+        for ((name, _, _), arg) in inputs.iter().zip(args) {
+            let Some(term) = self.operand_term(arg, span) else {
+                self.ctx.error(span, "unsupported argument in a call to a `#[may_panic]` function");
+                return;
+            };
+            terms.insert(*name, term);
+        }
+        cond.subst(&terms);
+
+        let allowed = self.may_panic().unwrap_or_else(|| Term::mk_false(self.tcx));
+        let span = span.source_callsite();
+        self.emit_statement(fmir::Statement::Assertion(Term { span, ..cond.implies(allowed) }));
+    }
+
+    fn operand_term(&mut self, operand: &Operand<'tcx>, span: Span) -> Option<Term<'tcx>> {
+        let ty = operand.ty(self.body, self.tcx);
+        if let Operand::Copy(pl) | Operand::Move(pl) = operand && let Some(locl) = pl.as_local() {
+            return Some(Term::var(self.translate_local(locl).symbol(), ty));
+        }
+
+        match self.translate_operand(operand) {
+            Expr::Constant(term) => Some(term),
+            Expr::Tuple(fields) if fields.is_empty() => {
+                Some(Term { ty, span, kind: TermKind::Tuple { fields: Vec::new() } })
+            }
+            _ => None,
+        }
+    }
 }
 
 pub(crate) fn resolve_function<'tcx>(
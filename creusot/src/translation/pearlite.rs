@@ -16,7 +16,6 @@ use itertools::Itertools;
 use log::*;
 use rustc_ast::{LitIntType, LitKind};
 use rustc_hir::{
-    self as hir,
     def_id::{DefId, LocalDefId},
     HirId, OwnerId,
 };
@@ -30,7 +29,7 @@ use rustc_middle::{
     },
     ty::{
         int_ty, subst::SubstsRef, uint_ty, AdtDef, Ty, TyCtxt, TyKind, TypeFoldable, TypeVisitable,
-        UpvarSubsts, WithOptConstParam,
+        WithOptConstParam,
     },
 };
 use rustc_span::{Span, Symbol, DUMMY_SP};
@@ -86,11 +85,13 @@ pub enum TermKind<'tcx> {
         arg: Box<Term<'tcx>>,
     },
     Forall {
-        binder: (Symbol, Ty<'tcx>),
+        binders: Vec<Binder<'tcx>>,
+        triggers: Vec<Trigger<'tcx>>,
         body: Box<Term<'tcx>>,
     },
     Exists {
-        binder: (Symbol, Ty<'tcx>),
+        binders: Vec<Binder<'tcx>>,
+        triggers: Vec<Trigger<'tcx>>,
         body: Box<Term<'tcx>>,
     },
     // TODO: Get rid of (id, subst).
@@ -139,6 +140,17 @@ pub enum TermKind<'tcx> {
     Old {
         term: Box<Term<'tcx>>,
     },
+    /// The value of `term` at a `label!`, or before the loop / at the start of the function when
+    /// `label` is `None`
+    At {
+        label: Option<Symbol>,
+        term: Box<Term<'tcx>>,
+    },
+    /// Assignment of a ghost variable of the enclosing function, in a `ghost!` block
+    Assign {
+        var: Symbol,
+        rhs: Box<Term<'tcx>>,
+    },
     Closure {
         args: Vec<Pattern<'tcx>>,
         body: Box<Term<'tcx>>,
@@ -181,6 +193,13 @@ pub enum Literal<'tcx> {
     Function(DefId, SubstsRef<'tcx>),
 }
 
+/// A trigger of a quantifier: it is instantiated for the ground terms matching all of `.0`.
+#[derive(Clone, Debug, TyDecodable, TyEncodable, TypeFoldable, TypeVisitable)]
+pub struct Trigger<'tcx>(pub Vec<Term<'tcx>>);
+
+/// A variable bound by a quantifier, along with its type.
+pub type Binder<'tcx> = (Symbol, Ty<'tcx>);
+
 #[derive(Clone, Debug, TyDecodable, TyEncodable, TypeFoldable, TypeVisitable)]
 pub enum Pattern<'tcx> {
     Constructor {
@@ -199,6 +218,33 @@ pub(crate) fn pearlite<'tcx>(
     ctx: &TranslationCtx<'tcx>,
     id: LocalDefId,
 ) -> CreusotResult<Term<'tcx>> {
+    lower_thir(ctx, id, |lower, expr| lower.body_term(expr))
+}
+
+fn closure_term<'tcx>(
+    ctx: &TranslationCtx<'tcx>,
+    id: LocalDefId,
+) -> CreusotResult<(Vec<Binder<'tcx>>, Term<'tcx>)> {
+    lower_thir(ctx, id, |lower, expr| Ok((lower.closure_binders()?, lower.body_term(expr)?)))
+}
+
+// Translates the closure of a quantifier, separating its body from its triggers.
+fn quant_closure_term<'tcx>(
+    ctx: &TranslationCtx<'tcx>,
+    id: LocalDefId,
+) -> CreusotResult<(Vec<Binder<'tcx>>, Vec<Trigger<'tcx>>, Term<'tcx>)> {
+    lower_thir(ctx, id, |lower, expr| {
+        let (triggers, body) = lower.quant_body_term(expr)?;
+        lower.check_trigger_vars(&triggers)?;
+        Ok((lower.closure_binders()?, triggers, body))
+    })
+}
+
+fn lower_thir<'tcx, R>(
+    ctx: &TranslationCtx<'tcx>,
+    id: LocalDefId,
+    f: impl FnOnce(&ThirTerm<'_, 'tcx>, ExprId) -> CreusotResult<R>,
+) -> CreusotResult<R> {
     let (thir, expr) = ctx.thir_body(WithOptConstParam::unknown(id)).map_err(|_| CrErr)?;
     let thir = thir.borrow();
     if thir.exprs.is_empty() {
@@ -212,7 +258,7 @@ pub(crate) fn pearlite<'tcx>(
 
     let lower = ThirTerm { ctx, item_id: id, thir: &thir };
 
-    lower.body_term(expr)
+    f(&lower, expr)
 }
 
 struct ThirTerm<'a, 'tcx> {
@@ -251,6 +297,72 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
         res
     }
 
+    // Parameters bound by a pattern are given an anonymous name, and destructured by `body_term`.
+    fn closure_binders(&self) -> CreusotResult<Vec<Binder<'tcx>>> {
+        self.thir
+            .params
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, param)| {
+                Some(self.pattern_term(param.pat.as_ref()?).map(|pat| match pat {
+                    Pattern::Binder(name) => (name, param.ty),
+                    _ => (util::anonymous_param_symbol(idx), param.ty),
+                }))
+            })
+            .collect()
+    }
+
+    // Variables bound by a parameter pattern are only introduced in the body, by `body_term`, so
+    // they are out of scope in the triggers of the quantifier.
+    fn check_trigger_vars(&self, triggers: &[Trigger<'tcx>]) -> CreusotResult<()> {
+        let mut pattern_vars = HashSet::new();
+        for param in self.thir.params.iter() {
+            let Some(pat) = &param.pat else { continue };
+            match self.pattern_term(pat)? {
+                Pattern::Binder(_) => {}
+                pattern => pattern.binds(&mut pattern_vars),
+            }
+        }
+
+        for term in triggers.iter().flat_map(|t| &t.0) {
+            let mut mentioned = MentionedVar(&pattern_vars, None);
+            mentioned.visit_term(term);
+            if let Some(var) = mentioned.1 {
+                return Err(Error::new(
+                    term.span,
+                    format!("triggers cannot mention `{var}`, which is bound by a pattern"),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    // Triggers are encoded as calls to the `trigger` stub wrapping the body of the quantifier.
+    fn quant_body_term(&self, expr: ExprId) -> CreusotResult<(Vec<Trigger<'tcx>>, Term<'tcx>)> {
+        let mut triggers = Vec::new();
+        let mut expr = expr;
+        loop {
+            match self.thir[expr].kind {
+                ExprKind::Scope { value, .. } => expr = value,
+                ExprKind::Block { block } => match self.thir[block] {
+                    Block { ref stmts, expr: Some(e), .. } if stmts.is_empty() => expr = e,
+                    _ => break,
+                },
+                ExprKind::Call { ty, ref args, .. }
+                    if matches!(pearlite_stub(self.ctx.tcx, ty), Some(Stub::Trigger)) =>
+                {
+                    match self.expr_term(args[0])?.kind {
+                        TermKind::Tuple { fields } => triggers.push(Trigger(fields)),
+                        _ => unreachable!("triggers are encoded as tuples"),
+                    };
+                    expr = args[1];
+                }
+                _ => break,
+            }
+        }
+        Ok((triggers, self.body_term(expr)?))
+    }
+
     fn expr_term(&self, expr: ExprId) -> CreusotResult<Term<'tcx>> {
         let ty = self.thir[expr].ty;
         let thir_term = &self.thir[expr];
@@ -369,14 +481,8 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                 use Stub::*;
                 match pearlite_stub(self.ctx.tcx, f_ty) {
                     Some(Forall) => {
-                        let (binder, body) = self.quant_term(args[0])?;
-                        let body = match type_invariant_term(
-                            self.ctx,
-                            self.item_id.to_def_id(),
-                            binder.0,
-                            span,
-                            binder.1.tuple_fields()[0],
-                        ) {
+                        let (binders, triggers, body) = self.quant_term(args[0])?;
+                        let body = match self.binders_invariant(&binders, span) {
                             Some(inv_term) => Term {
                                 ty,
                                 span,
@@ -391,18 +497,12 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                         Ok(Term {
                             ty,
                             span,
-                            kind: TermKind::Forall { binder, body: Box::new(body) },
+                            kind: TermKind::Forall { binders, triggers, body: Box::new(body) },
                         })
                     }
                     Some(Exists) => {
-                        let (binder, body) = self.quant_term(args[0])?;
-                        let body = match type_invariant_term(
-                            self.ctx,
-                            self.item_id.to_def_id(),
-                            binder.0,
-                            span,
-                            binder.1.tuple_fields()[0],
-                        ) {
+                        let (binders, triggers, body) = self.quant_term(args[0])?;
+                        let body = match self.binders_invariant(&binders, span) {
                             Some(inv_term) => Term {
                                 ty,
                                 span,
@@ -418,7 +518,7 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                         Ok(Term {
                             ty,
                             span,
-                            kind: TermKind::Exists { binder, body: Box::new(body) },
+                            kind: TermKind::Exists { binders, triggers, body: Box::new(body) },
                         })
                     }
                     Some(Fin) => {
@@ -475,6 +575,25 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
 
                         Ok(Term { ty, span, kind: TermKind::Old { term: Box::new(term) } })
                     }
+                    Some(At) => {
+                        let label = self.label_term(args[0])?;
+                        let term = self.expr_term(args[1])?;
+
+                        Ok(Term {
+                            ty,
+                            span,
+                            kind: TermKind::At { label: Some(label), term: Box::new(term) },
+                        })
+                    }
+                    Some(AtEntry) => {
+                        let term = self.expr_term(args[0])?;
+
+                        Ok(Term {
+                            ty,
+                            span,
+                            kind: TermKind::At { label: None, term: Box::new(term) },
+                        })
+                    }
                     Some(ResultCheck) => {
                         Ok(Term { ty, span, kind: TermKind::Tuple { fields: vec![] } })
                     }
@@ -482,6 +601,10 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                         Ok(Term { ty, span, kind: TermKind::Tuple { fields: vec![] } })
                     }
                     Some(Absurd) => Ok(Term { ty, span, kind: TermKind::Absurd }),
+                    Some(Trigger) => Err(Error::new(
+                        span,
+                        "triggers can only be attached to the body of a quantifier",
+                    )),
                     None => {
                         let fun = self.expr_term(fun)?;
                         let args = args
@@ -524,6 +647,12 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
             }
             // TODO: If we deref a shared borrow this should be erased?
             // Can it happen?
+            ExprKind::Assign { lhs, rhs } => {
+                let var = self.assigned_var(lhs)?;
+                let rhs = self.expr_term(rhs)?;
+
+                Ok(Term { ty, span, kind: TermKind::Assign { var, rhs: Box::new(rhs) } })
+            }
             ExprKind::Deref { arg } => {
                 if self.thir[arg].ty.is_box() || self.thir[arg].ty.ref_mutability() == Some(Not) {
                     let mut arg = self.expr_term(arg)?;
@@ -594,8 +723,8 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                 _ => Ok(Term { ty, span, kind: TermKind::Lit(Literal::ZST) }),
             },
             ExprKind::Closure(box ClosureExpr { closure_id, .. }) => {
-                let term = pearlite(self.ctx, closure_id)?;
-                let pats = closure_pattern(self.ctx.tcx, closure_id)?;
+                let (binders, term) = closure_term(self.ctx, closure_id)?;
+                let pats = binders.into_iter().map(|(name, _)| Pattern::Binder(name)).collect();
 
                 Ok(Term { ty, span, kind: TermKind::Closure { args: pats, body: Box::new(term) } })
             }
@@ -745,25 +874,58 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
         }
     }
 
-    fn quant_term(&self, body: ExprId) -> Result<((Symbol, Ty<'tcx>), Term<'tcx>), Error> {
+    fn quant_term(
+        &self,
+        body: ExprId,
+    ) -> CreusotResult<(Vec<Binder<'tcx>>, Vec<Trigger<'tcx>>, Term<'tcx>)> {
         trace!("{:?}", self.thir[body].kind);
         match self.thir[body].kind {
             ExprKind::Scope { value, .. } => self.quant_term(value),
-            ExprKind::Closure(box ClosureExpr { closure_id, substs, .. }) => {
-                let sig = match substs {
-                    UpvarSubsts::Closure(subst) => subst.as_closure().sig(),
-                    _ => unreachable!(),
-                };
-
-                let name = self.ctx.fn_arg_names(closure_id)[0];
-                let ty = sig.input(0).skip_binder();
-
-                Ok(((name.name, ty), pearlite(self.ctx, closure_id)?))
+            ExprKind::Closure(box ClosureExpr { closure_id, .. }) => {
+                quant_closure_term(self.ctx, closure_id)
             }
             _ => Err(Error::new(self.thir[body].span, "unexpected error in quantifier")),
         }
     }
 
+    // The variable assigned by ghost code, which must be captured from the enclosing function
+    fn assigned_var(&self, lhs: ExprId) -> CreusotResult<Symbol> {
+        match self.thir[lhs].kind {
+            ExprKind::Scope { value, .. } => self.assigned_var(value),
+            ExprKind::UpvarRef { var_hir_id: id, .. } => Ok(self.ctx.hir().name(id.0)),
+            _ => Err(Error::new(
+                self.thir[lhs].span,
+                "ghost code can only assign to ghost variables of the enclosing function",
+            )),
+        }
+    }
+
+    // The name of the label in `at!(label, term)`, passed as a string literal
+    fn label_term(&self, label: ExprId) -> CreusotResult<Symbol> {
+        match self.thir[label].kind {
+            ExprKind::Scope { value, .. } => self.label_term(value),
+            ExprKind::Literal { lit, .. } => match lit.node {
+                LitKind::Str(label, _) => Ok(label),
+                _ => Err(Error::new(self.thir[label].span, "unexpected error in label")),
+            },
+            _ => Err(Error::new(self.thir[label].span, "unexpected error in label")),
+        }
+    }
+
+    // The conjunction of the type invariants of the binders of a quantifier
+    fn binders_invariant(&self, binders: &[Binder<'tcx>], span: Span) -> Option<Term<'tcx>> {
+        binders
+            .iter()
+            .filter_map(|&(name, ty)| {
+                type_invariant_term(self.ctx, self.item_id.to_def_id(), name, span, ty)
+            })
+            .reduce(|acc, inv| Term {
+                ty: self.ctx.types.bool,
+                span,
+                kind: TermKind::Binary { op: BinOp::And, lhs: Box::new(acc), rhs: Box::new(inv) },
+            })
+    }
+
     // Creates a 'logical' reborrow of a mutable borrow.
     // The idea is that the expression `&mut ** X` for `X : &mut &mut T` should produces a pearlite value of type `&mut T`.
     //
@@ -894,9 +1056,12 @@ pub(crate) enum Stub {
     Neq,
     VariantCheck,
     Old,
+    At,
+    AtEntry,
     ResultCheck,
     Absurd,
     DummyCall,
+    Trigger,
 }
 
 pub(crate) fn pearlite_stub<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Option<Stub> {
@@ -913,6 +1078,9 @@ pub(crate) fn pearlite_stub<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Option<Stu
         if Some(*id) == tcx.get_diagnostic_item(Symbol::intern("cur")) {
             return Some(Stub::Cur);
         }
+        if Some(*id) == tcx.get_diagnostic_item(Symbol::intern("trigger")) {
+            return Some(Stub::Trigger);
+        }
         if Some(*id) == tcx.get_diagnostic_item(Symbol::intern("implication")) {
             return Some(Stub::Impl);
         }
@@ -928,6 +1096,12 @@ pub(crate) fn pearlite_stub<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Option<Stu
         if Some(*id) == tcx.get_diagnostic_item(Symbol::intern("old")) {
             return Some(Stub::Old);
         }
+        if Some(*id) == tcx.get_diagnostic_item(Symbol::intern("at")) {
+            return Some(Stub::At);
+        }
+        if Some(*id) == tcx.get_diagnostic_item(Symbol::intern("at_entry")) {
+            return Some(Stub::AtEntry);
+        }
         if Some(*id) == tcx.get_diagnostic_item(Symbol::intern("absurd")) {
             return Some(Stub::Absurd);
         }
@@ -986,6 +1160,9 @@ fn not_spec(tcx: TyCtxt<'_>, thir: &Thir<'_>, id: StmtId) -> bool {
 fn not_spec_expr(tcx: TyCtxt<'_>, thir: &Thir<'_>, id: ExprId) -> bool {
     match thir[id].kind {
         ExprKind::Scope { value, .. } => not_spec_expr(tcx, thir, value),
+        ExprKind::Block { block } if thir[block].stmts.is_empty() => {
+            thir[block].expr.map_or(true, |e| not_spec_expr(tcx, thir, e))
+        }
         ExprKind::Closure(box ClosureExpr { closure_id, .. }) => {
             !util::is_spec(tcx, closure_id.to_def_id())
         }
@@ -993,28 +1170,6 @@ fn not_spec_expr(tcx: TyCtxt<'_>, thir: &Thir<'_>, id: ExprId) -> bool {
     }
 }
 
-fn closure_pattern<'tcx>(tcx: TyCtxt<'tcx>, id: LocalDefId) -> CreusotResult<Vec<Pattern<'tcx>>> {
-    let body_id = tcx.hir().body_owned_by(id);
-    let body = tcx.hir().body(body_id);
-
-    body.params.iter().map(|p| lower_hir_pat(tcx, p.pat)).collect()
-}
-use rustc_hir;
-
-fn lower_hir_pat<'tcx>(tcx: TyCtxt<'tcx>, pat: &hir::Pat<'tcx>) -> CreusotResult<Pattern<'tcx>> {
-    use rustc_hir::PatKind;
-    match pat.kind {
-        PatKind::Tuple(pats, _) => {
-            let pats =
-                pats.into_iter().map(|p| lower_hir_pat(tcx, p)).collect::<Result<Vec<_>, _>>()?;
-            Ok(Pattern::Tuple(pats))
-        }
-        PatKind::Binding(_, _, id, _) => Ok(Pattern::Binder(id.name)),
-        PatKind::Wild => Ok(Pattern::Wildcard),
-        _ => Err(Error::new(pat.span, "unsupported pattern for Pearlite closure")),
-    }
-}
-
 impl<'tcx> Pattern<'tcx> {
     pub(crate) fn binds(&self, binders: &mut HashSet<Symbol>) {
         match self {
@@ -1034,7 +1189,6 @@ pub trait TermVisitor<'tcx> {
     fn visit_term(&mut self, term: &Term<'tcx>);
 }
 
-#[allow(dead_code)]
 pub fn super_visit_term<'tcx, V: TermVisitor<'tcx>>(term: &Term<'tcx>, visitor: &mut V) {
     match &term.kind {
         TermKind::Var(_) => {}
@@ -1045,8 +1199,14 @@ pub fn super_visit_term<'tcx, V: TermVisitor<'tcx>>(term: &Term<'tcx>, visitor:
             visitor.visit_term(&*rhs);
         }
         TermKind::Unary { op: _, arg } => visitor.visit_term(&*arg),
-        TermKind::Forall { binder: _, body } => visitor.visit_term(&*body),
-        TermKind::Exists { binder: _, body } => visitor.visit_term(&*body),
+        TermKind::Forall { binders: _, triggers, body } => {
+            triggers.iter().flat_map(|t| &t.0).for_each(|t| visitor.visit_term(t));
+            visitor.visit_term(&*body)
+        }
+        TermKind::Exists { binders: _, triggers, body } => {
+            triggers.iter().flat_map(|t| &t.0).for_each(|t| visitor.visit_term(t));
+            visitor.visit_term(&*body)
+        }
         TermKind::Call { id: _, subst: _, fun, args } => {
             visitor.visit_term(&*fun);
             args.iter().for_each(|a| visitor.visit_term(&*a))
@@ -1071,6 +1231,8 @@ pub fn super_visit_term<'tcx, V: TermVisitor<'tcx>>(term: &Term<'tcx>, visitor:
         }
         TermKind::Projection { lhs, name: _ } => visitor.visit_term(&*lhs),
         TermKind::Old { term } => visitor.visit_term(&*term),
+        TermKind::At { label: _, term } => visitor.visit_term(term),
+        TermKind::Assign { var: _, rhs } => visitor.visit_term(rhs),
         TermKind::Closure { args: _, body } => visitor.visit_term(&*body),
         TermKind::Absurd => {}
         TermKind::Reborrow { cur, fin } => {
@@ -1080,6 +1242,18 @@ pub fn super_visit_term<'tcx, V: TermVisitor<'tcx>>(term: &Term<'tcx>, visitor:
     }
 }
 
+// Finds a variable of the set occurring in a term
+struct MentionedVar<'a>(&'a HashSet<Symbol>, Option<Symbol>);
+
+impl<'tcx> TermVisitor<'tcx> for MentionedVar<'_> {
+    fn visit_term(&mut self, term: &Term<'tcx>) {
+        match term.kind {
+            TermKind::Var(v) if self.0.contains(&v) => self.1 = Some(v),
+            _ => super_visit_term(term, self),
+        }
+    }
+}
+
 pub trait TermVisitorMut<'tcx> {
     fn visit_mut_term(&mut self, term: &mut Term<'tcx>);
 }
@@ -1097,8 +1271,14 @@ pub(crate) fn super_visit_mut_term<'tcx, V: TermVisitorMut<'tcx>>(
             visitor.visit_mut_term(&mut *rhs);
         }
         TermKind::Unary { op: _, arg } => visitor.visit_mut_term(&mut *arg),
-        TermKind::Forall { binder: _, body } => visitor.visit_mut_term(&mut *body),
-        TermKind::Exists { binder: _, body } => visitor.visit_mut_term(&mut *body),
+        TermKind::Forall { binders: _, triggers, body } => {
+            triggers.iter_mut().flat_map(|t| &mut t.0).for_each(|t| visitor.visit_mut_term(t));
+            visitor.visit_mut_term(&mut *body)
+        }
+        TermKind::Exists { binders: _, triggers, body } => {
+            triggers.iter_mut().flat_map(|t| &mut t.0).for_each(|t| visitor.visit_mut_term(t));
+            visitor.visit_mut_term(&mut *body)
+        }
         TermKind::Call { id: _, subst: _, fun, args } => {
             visitor.visit_mut_term(&mut *fun);
             args.iter_mut().for_each(|a| visitor.visit_mut_term(&mut *a))
@@ -1125,6 +1305,8 @@ pub(crate) fn super_visit_mut_term<'tcx, V: TermVisitorMut<'tcx>>(
         }
         TermKind::Projection { lhs, name: _ } => visitor.visit_mut_term(&mut *lhs),
         TermKind::Old { term } => visitor.visit_mut_term(&mut *term),
+        TermKind::At { label: _, term } => visitor.visit_mut_term(&mut *term),
+        TermKind::Assign { var: _, rhs } => visitor.visit_mut_term(&mut *rhs),
         TermKind::Closure { args: _, body } => visitor.visit_mut_term(&mut *body),
         TermKind::Absurd => {}
         TermKind::Reborrow { cur, fin } => {
@@ -1139,6 +1321,10 @@ impl<'tcx> Term<'tcx> {
         Term { ty: tcx.types.bool, kind: TermKind::Lit(Literal::Bool(true)), span: DUMMY_SP }
     }
 
+    pub(crate) fn mk_false(tcx: TyCtxt<'tcx>) -> Self {
+        Term { ty: tcx.types.bool, kind: TermKind::Lit(Literal::Bool(false)), span: DUMMY_SP }
+    }
+
     pub(crate) fn var(sym: Symbol, ty: Ty<'tcx>) -> Self {
         Term { ty, kind: TermKind::Var(sym), span: DUMMY_SP }
     }
@@ -1227,16 +1413,24 @@ impl<'tcx> Term<'tcx> {
                 rhs.subst_inner(bound, inv_subst)
             }
             TermKind::Unary { arg, .. } => arg.subst_inner(bound, inv_subst),
-            TermKind::Forall { binder, body } => {
+            TermKind::Forall { binders, triggers, body } => {
                 let mut bound = bound.clone();
-                bound.insert(binder.0);
+                bound.extend(binders.iter().map(|b| b.0));
 
+                triggers
+                    .iter_mut()
+                    .flat_map(|t| &mut t.0)
+                    .for_each(|t| t.subst_inner(&bound, inv_subst));
                 body.subst_inner(&bound, inv_subst);
             }
-            TermKind::Exists { binder, body } => {
+            TermKind::Exists { binders, triggers, body } => {
                 let mut bound = bound.clone();
-                bound.insert(binder.0);
+                bound.extend(binders.iter().map(|b| b.0));
 
+                triggers
+                    .iter_mut()
+                    .flat_map(|t| &mut t.0)
+                    .for_each(|t| t.subst_inner(&bound, inv_subst));
                 body.subst_inner(&bound, inv_subst);
             }
             TermKind::Call { fun, args, .. } => {
@@ -1272,6 +1466,8 @@ impl<'tcx> Term<'tcx> {
             }
             TermKind::Projection { lhs, .. } => lhs.subst_inner(bound, inv_subst),
             TermKind::Old { term } => term.subst_inner(bound, inv_subst),
+            TermKind::At { term, .. } => term.subst_inner(bound, inv_subst),
+            TermKind::Assign { rhs, .. } => rhs.subst_inner(bound, inv_subst),
             TermKind::Closure { args, body } => {
                 let mut bound = bound.clone();
                 args.iter().for_each(|a| a.binds(&mut bound));
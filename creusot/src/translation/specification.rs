@@ -25,6 +25,8 @@ pub struct PreContract<'tcx> {
     pub(crate) variant: Option<Term<'tcx>>,
     pub(crate) requires: Vec<Term<'tcx>>,
     pub(crate) ensures: Vec<Term<'tcx>>,
+    // Condition under which the function is allowed to panic
+    pub(crate) may_panic: Option<Term<'tcx>>,
 }
 
 impl<'tcx> PreContract<'tcx> {
@@ -47,11 +49,19 @@ impl<'tcx> PreContract<'tcx> {
 
     #[allow(dead_code)]
     pub(crate) fn terms(&self) -> impl Iterator<Item = &Term<'tcx>> {
-        self.requires.iter().chain(self.ensures.iter()).chain(self.variant.iter())
+        self.requires
+            .iter()
+            .chain(self.ensures.iter())
+            .chain(self.variant.iter())
+            .chain(self.may_panic.iter())
     }
 
     fn terms_mut(&mut self) -> impl Iterator<Item = &mut Term<'tcx>> {
-        self.requires.iter_mut().chain(self.ensures.iter_mut()).chain(self.variant.iter_mut())
+        self.requires
+            .iter_mut()
+            .chain(self.ensures.iter_mut())
+            .chain(self.variant.iter_mut())
+            .chain(self.may_panic.iter_mut())
     }
 
     pub(crate) fn ensures_conj(&self, tcx: TyCtxt<'tcx>) -> Term<'tcx> {
@@ -76,11 +86,12 @@ pub struct ContractClauses {
     variant: Option<DefId>,
     requires: Vec<DefId>,
     ensures: Vec<DefId>,
+    may_panic: Option<DefId>,
 }
 
 impl ContractClauses {
     pub(crate) fn new() -> Self {
-        Self { variant: None, requires: Vec::new(), ensures: Vec::new() }
+        Self { variant: None, requires: Vec::new(), ensures: Vec::new(), may_panic: None }
     }
 
     fn get_pre<'tcx>(self, ctx: &mut TranslationCtx<'tcx>) -> EarlyBinder<PreContract<'tcx>> {
@@ -102,11 +113,22 @@ impl ContractClauses {
             let term = ctx.term(var_id).unwrap().clone();
             out.variant = Some(term);
         };
+
+        if let Some(panic_id) = self.may_panic {
+            log::trace!("may_panic clause {:?}", panic_id);
+            let term = ctx.term(panic_id).unwrap().clone();
+            out.may_panic = Some(term);
+        };
         EarlyBinder(out)
     }
 
     pub(crate) fn iter_ids(&self) -> impl Iterator<Item = DefId> + '_ {
-        self.requires.iter().chain(self.ensures.iter()).chain(self.variant.iter()).cloned()
+        self.requires
+            .iter()
+            .chain(self.ensures.iter())
+            .chain(self.variant.iter())
+            .chain(self.may_panic.iter())
+            .cloned()
     }
 }
 
@@ -170,15 +192,17 @@ impl ScopeTree {
     }
 }
 
+// The user variables in scope at a location, and the locals they are stored in
+pub(crate) fn visible_locals(body: &Body, loc: Location) -> HashMap<Symbol, Local> {
+    ScopeTree::build(body).visible_locals(body.source_info(loc).scope)
+}
+
 // Turn a typing context into a substition.
 pub(crate) fn inv_subst<'tcx>(body: &Body<'tcx>, loc: Location) -> HashMap<Symbol, Term<'tcx>> {
     // let local_map = real_locals(tcx, body);
-    let info = body.source_info(loc);
     let mut args = HashMap::new();
 
-    let tree = ScopeTree::build(body);
-
-    for (k, v) in tree.visible_locals(info.scope) {
+    for (k, v) in visible_locals(body, loc) {
         let loc = v;
         let ty = body.local_decls[loc].ty;
         let span = body.local_decls[loc].source_info.span;
@@ -230,6 +254,7 @@ pub(crate) fn contract_clauses_of(
             "requires" => contract.requires.push(get_creusot_item()?),
             "ensures" => contract.ensures.push(get_creusot_item()?),
             "variant" => contract.variant = Some(get_creusot_item()?),
+            "may_panic" => contract.may_panic = Some(get_creusot_item()?),
             _ => {}
         }
     }
@@ -248,13 +273,16 @@ pub(crate) fn inherited_extern_spec<'tcx>(
         }
 
         let assoc = ctx.opt_associated_item(def_id)?;
-        let trait_ref = ctx.impl_trait_ref(assoc.container_id(ctx.tcx))?;
+        let impl_id = assoc.container_id(ctx.tcx);
+        let trait_ref = ctx.impl_trait_ref(impl_id)?;
         let id = assoc.trait_item_def_id?;
 
         if ctx.extern_spec(id).is_none() {
             return None;
         }
-        (id, trait_ref.subst(ctx.tcx, subst).substs)
+        // The substitution of the trait method, including its own generic parameters
+        let trait_subst = trait_ref.subst(ctx.tcx, subst).substs;
+        (id, subst.rebase_onto(ctx.tcx, impl_id, trait_subst))
     }
 }
 
@@ -268,7 +296,8 @@ pub(crate) fn contract_of<'tcx>(
         contract.normalize(ctx.tcx, ctx.param_env(def_id))
     } else if let Some((parent_id, subst)) = inherited_extern_spec(ctx, def_id) {
         let spec = ctx.extern_spec(parent_id).cloned().unwrap();
-        let mut contract = spec.contract.get_pre(ctx).subst(ctx.tcx, subst);
+        let contract = spec.contract.get_pre(ctx).subst(ctx.tcx, spec.subst);
+        let mut contract = EarlyBinder(contract).subst(ctx.tcx, subst);
         contract.subst(&spec.arg_subst.iter().cloned().collect());
         contract.normalize(ctx.tcx, ctx.param_env(def_id))
     } else {
@@ -342,6 +371,15 @@ impl<'a, 'tcx> thir::visit::Visitor<'a, 'tcx> for PurityVisitor<'a, 'tcx> {
                     )
                 }
             }
+            ExprKind::Assign { lhs, .. } | ExprKind::AssignOp { lhs, .. } if self.in_pure_ctx => {
+                if !util::is_ghost_ty(self.tcx, self.thir[lhs].ty) {
+                    self.tcx.sess.span_err_with_code(
+                        expr.span,
+                        "ghost code can only assign to ghost variables",
+                        rustc_errors::DiagnosticId::Error(String::from("creusot")),
+                    );
+                }
+            }
             _ => {}
         }
         thir::visit::walk_expr(self, expr)
@@ -93,10 +93,27 @@ impl<'tcx> TranslationCtx<'tcx> {
                 self.param_env(impl_item),
                 self.def_span(impl_item),
             );
-            use rustc_trait_selection::traits::error_reporting::TypeErrCtxtExt;
             if let Err(errs) = res {
-                infcx.err_ctxt().report_fulfillment_errors(&errs);
-                self.crash_and_error(rustc_span::DUMMY_SP, "error above");
+                // The contract of an `extern_spec!` only applies to types satisfying its additional
+                // predicates, which calls check. When they can never hold, e.g. `PartialEq` for a
+                // type without a `DeepModel`, the impl has nothing to refine.
+                if errs.iter().all(|e| !e.obligation.predicate.has_non_region_param()) {
+                    continue;
+                }
+
+                let mut err = self.tcx.sess.struct_span_err(
+                    self.def_span(impl_item),
+                    format!(
+                        "cannot check that `{}` refines the `extern_spec!` of `{}`",
+                        self.def_path_str(impl_item),
+                        self.def_path_str(trait_item)
+                    ),
+                );
+                for e in &errs {
+                    err.note(format!("the specification requires `{}`", e.obligation.predicate));
+                }
+                err.help("add these bounds to the impl").emit();
+                continue;
             }
 
             let axiom = logic_refinement_term(self, impl_item, trait_item, refn_subst);
@@ -149,7 +166,8 @@ fn logic_refinement_term<'tcx>(
     let retty = impl_sig.output;
     let post_refn = Term {
         kind: TermKind::Forall {
-            binder: (Symbol::intern("result"), retty),
+            binders: vec![(Symbol::intern("result"), retty)],
+            triggers: Vec::new(),
             body: Box::new(impl_postcond.implies(trait_postcond)),
         },
         ty: ctx.tcx.types.bool,
@@ -160,11 +178,11 @@ fn logic_refinement_term<'tcx>(
     refn = if args.is_empty() {
         refn
     } else {
-        args.into_iter().rfold(refn, |acc, r| Term {
-            kind: TermKind::Forall { binder: r, body: Box::new(acc) },
+        Term {
+            kind: TermKind::Forall { binders: args, triggers: Vec::new(), body: Box::new(refn) },
             ty: ctx.tcx.types.bool,
             span,
-        })
+        }
     };
 
     refn
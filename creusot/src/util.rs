@@ -57,10 +57,23 @@ pub(crate) fn invariant_name(tcx: TyCtxt, def_id: DefId) -> Option<Symbol> {
     })
 }
 
+pub(crate) fn label_name(tcx: TyCtxt, def_id: DefId) -> Option<Symbol> {
+    get_attr(tcx.get_attrs_unchecked(def_id), &["creusot", "spec", "label"]).and_then(|a| match &a
+        .args
+    {
+        AttrArgs::Eq(_, AttrArgsEq::Hir(l)) => Some(l.symbol),
+        _ => None,
+    })
+}
+
 pub(crate) fn is_invariant(tcx: TyCtxt, def_id: DefId) -> bool {
     invariant_name(tcx, def_id).is_some()
 }
 
+pub(crate) fn is_loop_variant(tcx: TyCtxt, def_id: DefId) -> bool {
+    get_attr(tcx.get_attrs_unchecked(def_id), &["creusot", "spec", "loop_variant"]).is_some()
+}
+
 pub(crate) fn is_assertion(tcx: TyCtxt, def_id: DefId) -> bool {
     get_attr(tcx.get_attrs_unchecked(def_id), &["creusot", "spec", "assert"]).is_some()
 }
@@ -69,6 +82,15 @@ pub(crate) fn is_ghost(tcx: TyCtxt, def_id: DefId) -> bool {
     get_attr(tcx.get_attrs_unchecked(def_id), &["creusot", "spec", "ghost"]).is_some()
 }
 
+pub(crate) fn is_ghost_ty<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> bool {
+    match ty.kind() {
+        TyKind::Adt(adt, _) => {
+            get_builtin(tcx, adt.did()) == Some(Symbol::intern("prelude.Ghost.ghost_ty"))
+        }
+        _ => false,
+    }
+}
+
 pub(crate) fn is_ghost_closure<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Option<DefId> {
     if let TyKind::Closure(def_id, _) = ty.peel_refs().kind()  && is_ghost(tcx, *def_id)  {
         Some(*def_id)
@@ -83,6 +105,61 @@ pub(crate) fn is_logic(tcx: TyCtxt, def_id: DefId) -> bool {
     get_attr(tcx.get_attrs_unchecked(def_id), &["creusot", "decl", "logic"]).is_some()
 }
 
+pub(crate) fn is_lemma(tcx: TyCtxt, def_id: DefId) -> bool {
+    get_attr(tcx.get_attrs_unchecked(def_id), &["creusot", "decl", "lemma"]).is_some()
+}
+
+pub(crate) fn is_open(tcx: TyCtxt, def_id: DefId) -> bool {
+    get_attr(tcx.get_attrs_unchecked(def_id), &["creusot", "decl", "open"]).is_some()
+}
+
+pub(crate) fn is_opaque(tcx: TyCtxt, def_id: DefId) -> bool {
+    get_attr(tcx.get_attrs_unchecked(def_id), &["creusot", "decl", "opaque"]).is_some()
+}
+
+pub(crate) fn is_reveal(tcx: TyCtxt, def_id: DefId) -> bool {
+    get_attr(tcx.get_attrs_unchecked(def_id), &["creusot", "spec", "reveal"]).is_some()
+}
+
+pub(crate) fn is_terminates(tcx: TyCtxt, def_id: DefId) -> bool {
+    get_attr(tcx.get_attrs_unchecked(def_id), &["creusot", "decl", "terminates"]).is_some()
+}
+
+// The `reveal!` closures in the body of `def_id`
+pub(crate) fn reveal_closures(tcx: TyCtxt, def_id: DefId) -> impl Iterator<Item = DefId> + '_ {
+    tcx.hir()
+        .body_owners()
+        .map(|id| id.to_def_id())
+        .filter(move |id| is_reveal(tcx, *id) && tcx.typeck_root_def_id(*id) == def_id)
+}
+
+// The item named by a `reveal!` closure
+pub(crate) fn revealed_item(ctx: &mut TranslationCtx, closure: DefId) -> Option<DefId> {
+    match ctx.term(closure)?.kind {
+        TermKind::Item(id, _) => Some(id),
+        _ => None,
+    }
+}
+
+// Whether the body of `def_id` can be used in the proofs of `user`: the body of an `#[opaque]`
+// function is only visible in the module defining it, and in the functions revealing it.
+pub(crate) fn is_transparent_from(ctx: &mut TranslationCtx, def_id: DefId, user: DefId) -> bool {
+    if !is_opaque(ctx.tcx, def_id) {
+        return true;
+    }
+
+    let user = ctx.typeck_root_def_id(user);
+    if let (Some(local_id), true) = (def_id.as_local(), user.is_local()) {
+        let module = ctx.parent_module_from_def_id(local_id).to_def_id();
+        if ctx.is_descendant_of(user, module) {
+            return true;
+        }
+    }
+
+    let closures: Vec<_> = reveal_closures(ctx.tcx, user).collect();
+    closures.into_iter().any(|clos| revealed_item(ctx, clos) == Some(def_id))
+}
+
 pub(crate) fn is_trusted(tcx: TyCtxt, def_id: DefId) -> bool {
     get_attr(tcx.get_attrs_unchecked(def_id), &["creusot", "decl", "trusted"]).is_some()
 }
@@ -95,6 +172,12 @@ pub(crate) fn is_extern_spec(tcx: TyCtxt, def_id: DefId) -> bool {
     get_attr(tcx.get_attrs_unchecked(def_id), &["creusot", "extern_spec"]).is_some()
 }
 
+// The functions which `panic!`, `assert!` and the other panicking macros of `core` and `std` expand to
+pub(crate) fn is_panic_fn(tcx: TyCtxt, def_id: DefId) -> bool {
+    matches!(tcx.crate_name(def_id.krate).as_str(), "core" | "std")
+        && tcx.opt_item_name(tcx.parent(def_id)).map_or(false, |m| m.as_str() == "panicking")
+}
+
 pub(crate) fn is_type_invariant(tcx: TyCtxt, def_id: DefId) -> bool {
     let Some(assoc_item) = tcx.opt_associated_item(def_id) else { return false };
     let Some(trait_item_did) = (match assoc_item.container {
@@ -133,6 +216,70 @@ pub(crate) fn why3_attrs(tcx: TyCtxt, def_id: DefId) -> Vec<why3::declaration::A
         .collect()
 }
 
+// The Why3 attributes recording the prover, timeout and strategy expected by the goals of
+// `def_id`, given by `#[creusot::prover("name", timeout = seconds)]` and
+// `#[creusot::strategy("...")]`. Nothing runs the provers according to them.
+// Returns the span of the first malformed attribute.
+pub(crate) fn proof_attrs(tcx: TyCtxt, def_id: DefId) -> Result<Vec<declaration::Attribute>, Span> {
+    use rustc_ast::token::{Lit, LitKind, TokenKind};
+
+    let string = |tok: &TokenKind| match tok {
+        TokenKind::Literal(Lit { kind: LitKind::Str, symbol, suffix: None })
+            if !symbol.as_str().contains([']', '\n']) =>
+        {
+            Some(*symbol)
+        }
+        _ => None,
+    };
+
+    let mut out = Vec::new();
+    let attrs = tcx.get_attrs_unchecked(def_id);
+    for attr in get_attrs(attrs, &["creusot", "prover"]) {
+        let args = attr_tokens(attr).ok_or(attr.span)?;
+        let (prover, timeout) = match &args[..] {
+            [prover] => (string(prover), None),
+            [prover, TokenKind::Comma, TokenKind::Ident(key, false), TokenKind::Eq, TokenKind::Literal(Lit { kind: LitKind::Integer, symbol: timeout, suffix: None })]
+                if key.as_str() == "timeout" =>
+            {
+                (string(prover), Some(timeout))
+            }
+            _ => (None, None),
+        };
+
+        let prover = prover.ok_or(attr.span)?;
+        out.push(declaration::Attribute::Attr(format!("creusot:prover {prover}")));
+        if let Some(timeout) = timeout {
+            out.push(declaration::Attribute::Attr(format!("creusot:timeout {timeout}")));
+        }
+    }
+
+    for attr in get_attrs(attrs, &["creusot", "strategy"]) {
+        let args = attr_tokens(attr).ok_or(attr.span)?;
+        let [strategy] = &args[..] else { return Err(attr.span) };
+        let strategy = string(strategy).ok_or(attr.span)?;
+        out.push(declaration::Attribute::Attr(format!("creusot:strategy {strategy}")));
+    }
+
+    Ok(out)
+}
+
+// The tokens of an attribute of the form `#[path(tokens)]`
+fn attr_tokens(attr: &Attribute) -> Option<Vec<rustc_ast::token::TokenKind>> {
+    use rustc_ast::tokenstream::TokenTree;
+
+    match &attr.get_normal_item().args {
+        AttrArgs::Delimited(args) => args
+            .tokens
+            .trees()
+            .map(|tree| match tree {
+                TokenTree::Token(tok, _) => Some(tok.kind.clone()),
+                TokenTree::Delimited(..) => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
 pub(crate) fn param_def_id(tcx: TyCtxt, def_id: LocalDefId) -> LocalDefId {
     if is_spec(tcx, def_id.to_def_id()) && tcx.is_closure(def_id.to_def_id()) {
         tcx.parent(def_id.to_def_id()).expect_local()
@@ -654,16 +801,16 @@ impl<'tcx> TermVisitorMut<'tcx> for ClosureSubst<'tcx> {
                     }
                 }
             }
-            TermKind::Forall { binder, .. } => {
+            TermKind::Forall { binders, .. } => {
                 let mut bound = self.bound.clone();
-                bound.insert(binder.0);
+                bound.extend(binders.iter().map(|b| b.0));
                 std::mem::swap(&mut self.bound, &mut bound);
                 super_visit_mut_term(term, self);
                 std::mem::swap(&mut self.bound, &mut bound);
             }
-            TermKind::Exists { binder, .. } => {
+            TermKind::Exists { binders, .. } => {
                 let mut bound = self.bound.clone();
-                bound.insert(binder.0);
+                bound.extend(binders.iter().map(|b| b.0));
                 std::mem::swap(&mut self.bound, &mut bound);
                 super_visit_mut_term(term, self);
                 std::mem::swap(&mut self.bound, &mut bound);
@@ -1,9 +1,14 @@
-use rustc_hir::def::DefKind;
+use petgraph::{algo::tarjan_scc, graphmap::DiGraphMap};
+use rustc_errors::DiagnosticId;
+use rustc_hir::{def::DefKind, def_id::DefId};
 
 use crate::{
-    ctx::TranslationCtx,
-    translation::specification::is_overloaded_item,
-    util::{self, is_law},
+    ctx::{ItemType, TranslationCtx},
+    translation::{
+        pearlite::{super_visit_term, Pattern, Term, TermKind, TermVisitor},
+        specification::is_overloaded_item,
+    },
+    util::{self, is_law, is_lemma, is_opaque, is_open, is_reveal},
 };
 
 // Validate that laws have no additional generic parameters.
@@ -79,3 +84,155 @@ pub(crate) fn validate_impls(ctx: &TranslationCtx) {
         }
     }
 }
+
+// Validate that lemmas are proofs: they return `()`, their body is only made of calls to other
+// lemmas, and recursive lemmas have a variant.
+pub(crate) fn validate_lemmas(ctx: &mut TranslationCtx) {
+    let mut graph = DiGraphMap::<DefId, ()>::new();
+    for def_id in ctx.hir().body_owners() {
+        let def_id = def_id.to_def_id();
+        if !is_lemma(ctx.tcx, def_id) {
+            continue;
+        }
+
+        if !ctx.fn_sig(def_id).skip_binder().output().skip_binder().is_unit() {
+            ctx.error(ctx.def_span(def_id), "lemmas must return `()`");
+            continue;
+        }
+
+        if util::is_trusted(ctx.tcx, def_id) {
+            continue;
+        }
+
+        let Some(term) = ctx.term(def_id).cloned() else { continue };
+        if let Some(span) = invalid_proof_step(&term) {
+            ctx.error(
+                span,
+                "the body of a lemma must be made of lemma calls, `let`s, `if`s and `match`es",
+            );
+        }
+
+        graph.add_node(def_id);
+        for callee in calls(&term) {
+            if callee.is_local() && is_lemma(ctx.tcx, callee) && !util::is_trusted(ctx.tcx, callee)
+            {
+                graph.add_edge(def_id, callee, ());
+            }
+        }
+    }
+
+    for scc in tarjan_scc(&graph) {
+        // Each lemma is proved assuming the others of its cycle, whose variants are never checked
+        if scc.len() > 1 {
+            let cycle: Vec<_> =
+                scc.iter().map(|id| format!("`{}`", ctx.def_path_str(*id))).collect();
+            for &def_id in &scc {
+                ctx.sess
+                    .struct_span_err_with_code(
+                        ctx.def_span(def_id),
+                        format!(
+                            "lemma `{}` cannot be mutually recursive",
+                            ctx.def_path_str(def_id)
+                        ),
+                        DiagnosticId::Error(String::from("creusot")),
+                    )
+                    .note(format!("it is part of the recursive cycle {}", cycle.join(", ")))
+                    .emit();
+            }
+        } else if graph.contains_edge(scc[0], scc[0]) && ctx.sig(scc[0]).contract.variant.is_none()
+        {
+            ctx.error(ctx.def_span(scc[0]), "recursive lemmas must have a `#[variant]`");
+        }
+    }
+}
+
+// Finds a part of a lemma body which does not contribute to its proof
+fn invalid_proof_step(term: &Term) -> Option<rustc_span::Span> {
+    match &term.kind {
+        TermKind::Tuple { fields } if fields.is_empty() => None,
+        TermKind::Absurd => None,
+        TermKind::Call { .. } if term.ty.is_unit() => None,
+        TermKind::Let { pattern: Pattern::Wildcard, arg, body } => {
+            invalid_proof_step(arg).or_else(|| invalid_proof_step(body))
+        }
+        TermKind::Let { body, .. } => invalid_proof_step(body),
+        TermKind::Match { arms, .. } => arms.iter().find_map(|(_, arm)| invalid_proof_step(arm)),
+        _ => Some(term.span),
+    }
+}
+
+// The functions called by a term
+fn calls(term: &Term) -> Vec<DefId> {
+    struct Calls(Vec<DefId>);
+
+    impl<'tcx> TermVisitor<'tcx> for Calls {
+        fn visit_term(&mut self, term: &Term<'tcx>) {
+            if let TermKind::Call { id, .. } = term.kind {
+                self.0.push(id);
+            }
+            super_visit_term(term, self)
+        }
+    }
+
+    let mut visitor = Calls(Vec::new());
+    visitor.visit_term(term);
+    visitor.0
+}
+
+// Validate that only logical functions are `#[open]` or `#[opaque]`, and that `reveal!` is only
+// used on `#[opaque]` functions.
+pub(crate) fn validate_opacity(ctx: &mut TranslationCtx) {
+    for def_id in ctx.hir().body_owners() {
+        let def_id = def_id.to_def_id();
+        let (open, opaque) = (is_open(ctx.tcx, def_id), is_opaque(ctx.tcx, def_id));
+
+        if (open || opaque)
+            && !matches!(util::item_type(ctx.tcx, def_id), ItemType::Logic | ItemType::Predicate)
+        {
+            ctx.error(
+                ctx.def_span(def_id),
+                "only logical functions can be `#[open]` or `#[opaque]`",
+            );
+        } else if open && opaque {
+            ctx.error(ctx.def_span(def_id), "a function cannot be both `#[open]` and `#[opaque]`");
+        }
+
+        if is_reveal(ctx.tcx, def_id) {
+            let revealed = util::revealed_item(ctx, def_id);
+            if !revealed.map_or(false, |id| is_opaque(ctx.tcx, id)) {
+                ctx.error(
+                    ctx.def_span(def_id),
+                    "`reveal!` expects an `#[opaque]` logical function",
+                );
+            }
+        }
+    }
+}
+
+// Validate the syntax of `#[creusot::prover]` and `#[creusot::strategy]`.
+pub(crate) fn validate_proof_attrs(ctx: &mut TranslationCtx) {
+    for def_id in ctx.hir().body_owners() {
+        if let Err(span) = util::proof_attrs(ctx.tcx, def_id.to_def_id()) {
+            ctx.error(
+                span,
+                "expected `#[creusot::prover(\"prover\", timeout = seconds)]` or `#[creusot::strategy(\"strategy\")]`",
+            );
+        }
+    }
+
+    // Trait methods without a default body have no goals to prove
+    for trait_item_id in ctx.hir_crate_items(()).trait_items() {
+        let def_id = trait_item_id.owner_id.def_id;
+        if ctx.hir().maybe_body_owned_by(def_id).is_some() {
+            continue;
+        }
+
+        let attrs = ctx.get_attrs_unchecked(def_id.to_def_id());
+        for attr in util::get_attrs(attrs, &["creusot", "prover"])
+            .into_iter()
+            .chain(util::get_attrs(attrs, &["creusot", "strategy"]))
+        {
+            ctx.error(attr.span, "proof attributes have no effect on functions without a body");
+        }
+    }
+}
@@ -0,0 +1,36 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[lemma]
+#[ensures(result == 0)]
+fn returns_value() -> Int {
+    0
+}
+
+#[lemma]
+#[requires(n >= 0)]
+#[ensures(n + 1 > 0)]
+fn not_a_proof(n: Int) {
+    let _ = n + 1;
+    pearlite! { n >= 0 };
+}
+
+#[lemma]
+#[ensures(n == n)]
+fn no_variant(n: Int) {
+    no_variant(n - 1)
+}
+
+#[lemma]
+#[variant(0)]
+#[ensures(false)]
+fn ping() {
+    pong()
+}
+
+#[lemma]
+#[variant(0)]
+#[ensures(false)]
+fn pong() {
+    ping()
+}
@@ -0,0 +1,18 @@
+extern crate creusot_contracts;
+
+#[creusot::prover(z3)]
+pub fn unquoted() {}
+
+#[creusot::prover("z3", time = 30)]
+pub fn unknown_key() {}
+
+#[creusot::strategy("split_vc", "auto")]
+pub fn two_strategies() {}
+
+pub trait Required {
+    #[creusot::prover("z3")]
+    fn required();
+
+    #[creusot::strategy("split_vc")]
+    fn provided() {}
+}
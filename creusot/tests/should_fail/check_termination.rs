@@ -0,0 +1,15 @@
+// CREUSOT_ARG=--check-termination
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub fn spin() {
+    loop {}
+}
+
+pub fn count(mut n: u32) -> u32 {
+    #[variant(n)]
+    while n > 0 {
+        n -= 1;
+    }
+    count(n)
+}
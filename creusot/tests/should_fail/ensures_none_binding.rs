@@ -0,0 +1,7 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures_none(x => x == 0u32)]
+pub fn nothing() -> Option<u32> {
+    None
+}
@@ -0,0 +1,10 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub fn f() {
+    let mut x = 0u32;
+    ghost! {
+        x = 1u32;
+    };
+    proof_assert!(x == 1u32);
+}
@@ -0,0 +1,10 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub fn two_variants(mut n: u32) {
+    #[variant(n)]
+    #[variant(@n)]
+    while n > 0 {
+        n -= 1;
+    }
+}
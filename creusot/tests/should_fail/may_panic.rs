@@ -0,0 +1,13 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Div {
+    fn div(self, d: u32) -> u32;
+}
+
+impl Div for u32 {
+    #[may_panic(d == 0u32)]
+    fn div(self, d: u32) -> u32 {
+        self / d
+    }
+}
@@ -0,0 +1,21 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[logic]
+fn transparent(x: Int) -> Int {
+    x
+}
+
+#[opaque]
+pub fn program() {}
+
+#[open]
+#[opaque]
+#[logic]
+fn both(x: Int) -> Int {
+    x
+}
+
+pub fn reveal_transparent() {
+    reveal!(transparent);
+}
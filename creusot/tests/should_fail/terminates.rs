@@ -0,0 +1,59 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub fn may_diverge() {}
+
+#[terminates]
+pub fn calls_diverging() {
+    may_diverge();
+}
+
+#[terminates]
+pub fn loop_without_variant(mut n: u32) {
+    while n > 0 {
+        n -= 1;
+    }
+}
+
+#[terminates]
+pub fn recursion_without_variant(n: u32) -> u32 {
+    if n == 0 {
+        0
+    } else {
+        recursion_without_variant(n - 1)
+    }
+}
+
+pub trait Measure {
+    fn measure(&self) -> u32;
+}
+
+#[terminates]
+pub fn generic_call<T: Measure>(x: &T) -> u32 {
+    x.measure()
+}
+
+#[terminates]
+pub fn closure_call<F: Fn(u32) -> u32>(f: F) -> u32 {
+    f(0)
+}
+
+#[terminates]
+#[variant(0)]
+pub fn ping(n: u32) -> u32 {
+    pong(n)
+}
+
+#[terminates]
+#[variant(0)]
+pub fn pong(n: u32) -> u32 {
+    ping(n)
+}
+
+// `MapInv` is verified by Creusot, but its `next` is not `#[terminates]`
+#[terminates]
+pub fn verified_extern_call<I: Iterator, F: FnMut(I::Item, Ghost<Seq<I::Item>>) -> u32>(
+    it: &mut creusot_contracts::std::iter::MapInv<I, I::Item, F>,
+) -> Option<u32> {
+    it.next()
+}
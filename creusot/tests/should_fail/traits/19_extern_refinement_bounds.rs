@@ -0,0 +1,20 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub struct Wrap<T>(pub T);
+
+impl<T: DeepModel> DeepModel for Wrap<T> {
+    type DeepModelTy = T::DeepModelTy;
+
+    #[logic]
+    fn deep_model(self) -> T::DeepModelTy {
+        self.0.deep_model()
+    }
+}
+
+// The specification of `PartialEq::eq` only holds when `Wrap<T>: DeepModel`
+impl<T> PartialEq for Wrap<T> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
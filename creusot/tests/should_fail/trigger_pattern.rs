@@ -0,0 +1,12 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[logic]
+fn f(_: Int) -> Int {
+    pearlite! { 0 }
+}
+
+#[predicate]
+pub fn pattern_binder_in_trigger() -> bool {
+    pearlite! { forall<(a, b): (Int, Int)> #[trigger(f(a))] f(a) == f(b) }
+}
@@ -0,0 +1,8 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub fn f(mut x: u32) {
+    label!(a);
+    x += 1;
+    proof_assert!(at!(b, x) == 0u32);
+}
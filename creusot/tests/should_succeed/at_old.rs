@@ -0,0 +1,39 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[requires((@^v).len() == (@v).len())]
+#[ensures(forall<i : Int> 0 <= i && i < (@v).len() ==> @(@^v)[i] == @(@v)[i] + 1)]
+pub fn increment(v: &mut Vec<u32>) {
+    let mut i = 0;
+    #[invariant(len, (@v).len() == (@old!(v)).len())]
+    #[invariant(done, forall<j : Int> 0 <= j && j < @i ==> @(@v)[j] == @(@old!(v))[j] + 1)]
+    #[invariant(todo, forall<j : Int> @i <= j && j < (@v).len() ==> (@v)[j] == (@old!(v))[j])]
+    #[invariant(proph, ^v == ^old!(v))]
+    while i < v.len() {
+        v[i] = v[i].wrapping_add(1);
+        i += 1;
+    }
+}
+
+pub fn swap_labels(mut a: u32, mut b: u32) {
+    label!(start);
+    let t = a;
+    a = b;
+    b = t;
+    proof_assert!(a == at!(start, b) && b == at!(start, a));
+    proof_assert!(a == old!(b));
+}
+
+#[requires(@n < 1000)]
+pub fn count_up(n: u32) {
+    let mut x = 0;
+    let mut i = 0;
+    label!(before);
+    #[invariant(bounded, @i <= @n)]
+    #[invariant(counted, @x == @at!(before, x) + 2 * @i)]
+    while i < n {
+        x += 2;
+        i += 1;
+    }
+    proof_assert!(@x == 2 * @n);
+}
@@ -0,0 +1,34 @@
+// CREUSOT_ARG=--audit CREUSOT_ARG=--focus-on=client
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[trusted]
+#[ensures(result == 0u32)]
+fn oracle() -> u32 {
+    0
+}
+
+#[ensures(@result == @x + 1)]
+#[requires(@x < 1000)]
+fn incr(x: u32) -> u32 {
+    x + 1
+}
+
+// The contract of `Vec::push` uses the `Seq::push` builtin
+pub fn client(v: &mut Vec<u32>) {
+    let x = oracle();
+    v.push(incr(x));
+}
+
+// Not reached from `client`, so not part of its trust base
+#[trusted]
+#[ensures(result == 1u32)]
+pub fn unrelated() -> u32 {
+    1
+}
+
+// `Vec::len` is not verified by Creusot, so it is assumed to terminate
+#[terminates]
+pub fn client_len(v: &Vec<u32>) -> usize {
+    v.len()
+}
@@ -0,0 +1,42 @@
+// CREUSOT_ARG=--coverage
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[logic]
+fn double(x: Int) -> Int {
+    x * 2
+}
+
+#[requires(x < 100u32)]
+#[ensures(@result == double(@x))]
+pub fn verified(x: u32) -> u32 {
+    x * 2
+}
+
+pub fn unspecified(v: &Vec<u32>) -> usize {
+    v.len()
+}
+
+#[trusted]
+#[ensures(result == 0u32)]
+pub fn trusted() -> u32 {
+    0
+}
+
+pub mod inner {
+    use creusot_contracts::*;
+
+    #[predicate]
+    pub fn is_zero(x: u32) -> bool {
+        pearlite! { x == 0u32 }
+    }
+
+    #[ensures(is_zero(result))]
+    pub fn zero() -> u32 {
+        0
+    }
+
+    pub fn push(v: &mut Vec<u32>) {
+        v.push(0)
+    }
+}
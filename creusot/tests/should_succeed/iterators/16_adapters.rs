@@ -0,0 +1,63 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub fn filter_even(v: &Vec<u32>) {
+    #[invariant(even, forall<i: Int> 0 <= i && i < produced.len() ==> @*produced[i] % 2 == 0)]
+    for x in v.iter().filter(
+        #[ensures(result == (@**x % 2 == 0))]
+        |x| { **x % 2 == 0 },
+    ) {
+        let _ = x;
+    }
+}
+
+#[requires((@a).len() == (@b).len())]
+pub fn zip_pairs(a: &Vec<u32>, b: &Vec<u32>) {
+    #[invariant(pairs, forall<i: Int> 0 <= i && i < produced.len() ==>
+        *produced[i].0 == (@a)[i] && *produced[i].1 == (@b)[i])]
+    for (x, y) in a.iter().zip(b.iter()) {
+        let _ = (x, y);
+    }
+}
+
+pub fn chain_ranges() {
+    let mut cnt = 0;
+    #[invariant(count, @cnt <= 20)]
+    for _ in (0..10u32).chain(0..10u32) {
+        if cnt < 20 {
+            cnt += 1;
+        }
+    }
+}
+
+#[requires(@n < 1000)]
+pub fn rev_range(n: usize) {
+    #[invariant(decreasing, forall<i: Int> 0 <= i && i < produced.len() ==> @produced[i] == @n - 1 - i)]
+    for i in (0..n).rev() {
+        let _ = i;
+    }
+}
+
+pub fn step_by_range() {
+    #[invariant(steps, forall<i: Int> 0 <= i && i < produced.len() ==> @produced[i] == 3 * i)]
+    for i in (0..10u32).step_by(3) {
+        let _ = i;
+    }
+}
+
+pub fn peek_first(v: &Vec<u32>) {
+    let mut it = v.iter().peekable();
+    if let Some(x) = it.peek() {
+        let x = **x;
+        let y = it.next();
+        proof_assert! { match y { Some(y) => *y == x, None => false } };
+    }
+}
+
+pub fn fuse_twice(v: Vec<u32>) {
+    let mut it = v.into_iter().fuse();
+    if it.next().is_none() {
+        let r = it.next();
+        proof_assert! { r == None };
+    }
+}
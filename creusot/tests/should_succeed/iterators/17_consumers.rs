@@ -0,0 +1,55 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[requires((@v).len() < 1000)]
+#[ensures(@result == (@v).len())]
+pub fn count_elems(v: &Vec<u32>) -> usize {
+    v.iter().count()
+}
+
+#[requires(forall<i: Int> 0 <= i && i < (@v).len() ==> @(@v)[i] <= 10)]
+#[requires((@v).len() < 1000)]
+pub fn sum_elems(v: &Vec<u32>) -> u32 {
+    v.iter().sum()
+}
+
+pub fn fold_max(v: &Vec<u32>) -> u32 {
+    v.iter().fold(
+        0,
+        #[ensures(@result >= @acc && @result >= @*x)]
+        |acc, x| {
+            if *x > acc {
+                *x
+            } else {
+                acc
+            }
+        },
+    )
+}
+
+#[ensures(result == exists<i: Int> 0 <= i && i < (@v).len() && (@v)[i] == 0u32)]
+pub fn has_zero(v: &Vec<u32>) -> bool {
+    v.iter().any(
+        #[ensures(result == (*x == 0u32))]
+        |x| { *x == 0 },
+    )
+}
+
+#[ensures(result == forall<i: Int> 0 <= i && i < (@v).len() ==> @(@v)[i] > 0)]
+pub fn all_positive(v: &Vec<u32>) -> bool {
+    v.iter().all(
+        #[ensures(result == (@*x > 0))]
+        |x| { *x > 0 },
+    )
+}
+
+#[ensures(match result {
+    Some(i) => (@v)[@i] == 0u32,
+    None => forall<i: Int> 0 <= i && i < (@v).len() ==> (@v)[i] != 0u32,
+})]
+pub fn find_zero(v: &Vec<u32>) -> Option<usize> {
+    v.iter().position(
+        #[ensures(result == (*x == 0u32))]
+        |x| { *x == 0 },
+    )
+}
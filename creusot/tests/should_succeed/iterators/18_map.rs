@@ -0,0 +1,44 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[requires(forall<i: Int> 0 <= i && i < (@v).len() ==> @(@v)[i] < 100)]
+#[ensures((@result).len() == (@v).len())]
+#[ensures(forall<i: Int> 0 <= i && i < (@v).len() ==> @(@result)[i] == @(@v)[i] + 1)]
+pub fn increment(v: &Vec<u32>) -> Vec<u32> {
+    v.iter()
+        .map(
+            #[requires(@*x < 100)]
+            #[ensures(@result == @*x + 1)]
+            |x| *x + 1,
+        )
+        .collect()
+}
+
+#[requires(@n < 1000)]
+pub fn squares(n: u32) {
+    #[invariant(squares, forall<i: Int> 0 <= i && i < produced.len() ==> @produced[i] == i * i)]
+    for sq in (0..n).map(
+        #[requires(@i < 1000)]
+        #[ensures(@result == @i * @i)]
+        |i| i * i,
+    ) {
+        let _ = sq;
+    }
+}
+
+// The closure may update its captured state
+pub fn last(v: &Vec<u32>) -> u32 {
+    let mut last = 0;
+    let x: Vec<u32> = v
+        .iter()
+        .map(
+            #[ensures(last == *x && result == *x)]
+            |x| {
+                last = *x;
+                *x
+            },
+        )
+        .collect();
+    proof_assert! { (@x).len() == (@v).len() };
+    last
+}
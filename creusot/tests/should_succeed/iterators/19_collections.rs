@@ -0,0 +1,87 @@
+extern crate creusot_contracts;
+use creusot_contracts::{
+    std::collections::{BTreeMap, HashMap, VecDeque},
+    *,
+};
+
+#[ensures(@result == (@v).len())]
+#[ensures((@^v).len() == 0)]
+pub fn drain_all(v: &mut Vec<u32>) -> usize {
+    let mut n = 0;
+    #[invariant(count, @n == produced.len())]
+    for x in v.drain(..) {
+        let _ = x;
+        n += 1;
+    }
+    n
+}
+
+#[requires(@from <= @to && @to <= (@v).len())]
+#[ensures((@^v).len() <= (@v).len() - (@to - @from))]
+pub fn drain_range(v: &mut Vec<u32>, from: usize, to: usize) {
+    #[invariant(drained, forall<i: Int> 0 <= i && i < produced.len() ==> produced[i] == (@v)[@from + i])]
+    for x in v.drain(from..to) {
+        let _ = x;
+    }
+}
+
+#[requires(@from <= @to && @to < (@v).len())]
+#[ensures((@^v).len() <= (@v).len() - (@to - @from + 1))]
+pub fn drain_inclusive(v: &mut Vec<u32>, from: usize, to: usize) {
+    #[invariant(drained, forall<i: Int> 0 <= i && i < produced.len() ==> produced[i] == (@v)[@from + i])]
+    for x in v.drain(from..=to) {
+        let _ = x;
+    }
+}
+
+#[ensures(@result == (@d).len())]
+pub fn deque_len(d: VecDeque<u32>) -> usize {
+    let mut n = 0;
+    #[invariant(count, @n == produced.len())]
+    for x in d {
+        let _ = x;
+        n += 1;
+    }
+    n
+}
+
+pub fn deque_iter(d: &VecDeque<u32>) {
+    #[invariant(elems, forall<i: Int> 0 <= i && i < produced.len() ==> *produced[i] == (@d)[i])]
+    for x in d {
+        let _ = x;
+    }
+}
+
+#[ensures(@result == (@s).len())]
+pub fn count_chars(s: &str) -> usize {
+    let mut n = 0;
+    #[invariant(count, @n == produced.len())]
+    for c in s.chars() {
+        let _ = c;
+        n += 1;
+    }
+    n
+}
+
+pub fn hash_values(m: &HashMap<u32, u32>) {
+    #[invariant(entries, forall<i: Int> 0 <= i && i < produced.len() ==>
+        (@m).get(produced[i].0.deep_model()) == Some(*produced[i].1))]
+    for (k, v) in m {
+        let _ = (k, v);
+    }
+}
+
+pub fn btree_values(m: &BTreeMap<u32, u32>) {
+    #[invariant(entries, forall<i: Int> 0 <= i && i < produced.len() ==>
+        (@m).get(produced[i].0.deep_model()) == Some(*produced[i].1))]
+    for (k, v) in m.iter() {
+        let _ = (k, v);
+    }
+}
+
+pub fn insert_then_get() {
+    let mut m = HashMap::new();
+    m.insert(1u32, 10u32);
+    proof_assert!((@m).get(1) == Some(10u32));
+    assert!(m.contains_key(&1));
+}
@@ -0,0 +1,46 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[logic]
+#[variant(n)]
+#[requires(n >= 0)]
+fn sum_to(n: Int) -> Int {
+    pearlite! {
+        if n == 0 { 0 } else { n + sum_to(n - 1) }
+    }
+}
+
+#[lemma]
+#[variant(n)]
+#[requires(n >= 0)]
+#[ensures(sum_to(n) * 2 == n * (n + 1))]
+fn sum_to_closed_form(n: Int) {
+    pearlite! {
+        if n > 0 {
+            sum_to_closed_form(n - 1)
+        }
+    }
+}
+
+#[lemma]
+#[requires(0 <= m && m <= n)]
+#[ensures(sum_to(m) <= sum_to(n))]
+fn sum_to_monotonic(m: Int, n: Int) {
+    sum_to_closed_form(m);
+    sum_to_closed_form(n)
+}
+
+#[requires(@n <= 1000)]
+#[ensures(@result * 2 == @n * (@n + 1))]
+pub fn gauss(n: u32) -> u32 {
+    let mut i = 0;
+    let mut sum = 0;
+    #[invariant(bound, @i <= @n)]
+    #[invariant(sum, @sum == sum_to(@i))]
+    while i < n {
+        i += 1;
+        sum += i;
+    }
+    proof_assert! { sum_to_closed_form(@n); true };
+    sum
+}
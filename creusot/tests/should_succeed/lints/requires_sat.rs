@@ -0,0 +1,22 @@
+// CREUSOT_ARG=--check-requires-sat
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[requires(@x < 10)]
+#[ensures(@result == @x + 1)]
+pub fn satisfiable(x: u32) -> u32 {
+    x + 1
+}
+
+#[requires(@x > 10 && @x < 5)]
+#[ensures(result == 0u32)]
+pub fn unsatisfiable(x: u32) -> u32 {
+    x
+}
+
+#[requires(@a < @b)]
+pub fn several(a: u32, b: u32) {}
+
+pub fn no_requires(x: u32) -> u32 {
+    x
+}
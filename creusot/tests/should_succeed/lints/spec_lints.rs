@@ -0,0 +1,75 @@
+#![warn(creusot::unused_logic)]
+#![warn(
+    creusot::contradictory_requires,
+    creusot::unconstrained_result,
+    creusot::trusted_without_contract
+)]
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[requires(false)]
+pub fn unreachable_requires() {}
+
+#[requires(@x > 10 && 1 > 2)]
+pub fn contradictory(x: u32) {}
+
+#[requires(@2u32 + 1 > 3)]
+pub fn contradictory_arithmetic() {}
+
+#[ensures(@x > 0)]
+pub fn unconstrained(x: u32) -> u32 {
+    x
+}
+
+#[trusted]
+pub fn no_contract() {}
+
+#[logic]
+pub fn unused_helper(x: Int) -> Int {
+    x + 1
+}
+
+#[logic]
+#[variant(x)]
+#[requires(x >= 0)]
+pub fn recursive(x: Int) -> Int {
+    if x == 0 {
+        0
+    } else {
+        recursive(x - 1)
+    }
+}
+
+#[predicate]
+fn used_in_spec(x: u32) -> bool {
+    pearlite! { @x < 100 }
+}
+
+// None of the following should trigger a lint
+
+#[requires(used_in_spec(x))]
+#[ensures(@result == @x + 1)]
+pub fn constrained(x: u32) -> u32 {
+    x + 1
+}
+
+#[ensures(false)]
+pub fn diverges() -> u32 {
+    loop {}
+}
+
+#[ensures(^x == 0u32)]
+pub fn constrained_by_final(x: &mut u32) -> bool {
+    *x = 0;
+    true
+}
+
+#[trusted]
+#[ensures(result == 0u32)]
+pub fn trusted_with_contract() -> u32 {
+    0
+}
+
+#[allow(creusot::trusted_without_contract)]
+#[trusted]
+pub fn allowed() {}
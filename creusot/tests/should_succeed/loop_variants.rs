@@ -0,0 +1,32 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub fn countdown(mut n: u32) {
+    #[variant(n)]
+    while n > 0 {
+        n -= 1;
+    }
+}
+
+#[requires(@n <= @max)]
+pub fn retry(mut n: u32, max: u32) -> u32 {
+    #[invariant(bounded, @n <= @max)]
+    #[variant(@max - @n)]
+    loop {
+        if n == max {
+            break;
+        }
+        n += 1;
+    }
+    n
+}
+
+pub fn sum_to(n: u32) -> u64 {
+    let mut sum = 0u64;
+    #[invariant(bounded, @sum <= produced.len() * 0xFFFF_FFFF)]
+    #[variant(@n - produced.len())]
+    for i in 0..n {
+        sum += i as u64;
+    }
+    sum
+}
@@ -0,0 +1,51 @@
+// CREUSOT_ARG=--panic-report
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[may_panic(d == 0u32)]
+#[ensures(result == n / d)]
+pub fn checked_div(n: u32, d: u32) -> u32 {
+    if d == 0 {
+        panic!()
+    }
+    n / d
+}
+
+#[may_panic(@i >= 4)]
+pub fn get(a: [u32; 4], i: usize) -> u32 {
+    a[i]
+}
+
+#[may_panic]
+pub fn limited(x: u32) -> u32 {
+    assert!(x < 100);
+    x
+}
+
+#[may_panic(d == 0u32)]
+pub fn forward(n: u32, d: u32) -> u32 {
+    checked_div(n, d)
+}
+
+pub fn halve(n: u32) -> u32 {
+    checked_div(n, 2)
+}
+
+#[requires(@i < 4)]
+pub fn get_in_bounds(a: [u32; 4], i: usize) -> u32 {
+    get(a, i)
+}
+
+#[trusted]
+pub fn unchecked(x: u32) -> u32 {
+    limited(x)
+}
+
+// Exiting is not panicking: like any call which does not return, it must be unreachable
+#[may_panic(code == 0i32)]
+#[requires(code != 1i32)]
+pub fn exit_on_one(code: i32) {
+    if code == 1 {
+        std::process::exit(code)
+    }
+}
@@ -0,0 +1,52 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub mod square {
+    use creusot_contracts::*;
+
+    #[opaque]
+    #[logic]
+    #[ensures(result >= 0)]
+    pub fn square(x: Int) -> Int {
+        x * x
+    }
+
+    #[open]
+    #[logic]
+    pub fn cube(x: Int) -> Int {
+        x * square(x)
+    }
+
+    // The body of `square` is visible in its module
+    #[ensures(@result == square(2))]
+    pub fn four() -> i32 {
+        4
+    }
+}
+
+use square::{cube, square};
+
+// Outside of its module, only the contract of `square` is known
+#[ensures(square(@x) >= 0)]
+pub fn non_negative(x: i32) {}
+
+#[ensures(cube(2) == 2 * square(2))]
+pub fn cube_two() {}
+
+#[ensures(square(3) == 9)]
+pub fn revealed() {
+    reveal!(square);
+}
+
+#[lemma]
+#[ensures(square(x) == x * x)]
+fn square_def(x: Int) {
+    reveal!(square);
+}
+
+#[requires(@x <= 100 && @x >= -100)]
+#[ensures(@result == square(@x))]
+pub fn compute(x: i32) -> i32 {
+    proof_assert! { square_def(@x); true };
+    x * x
+}
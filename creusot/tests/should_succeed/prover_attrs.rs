@@ -0,0 +1,20 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[creusot::prover("z3", timeout = 30)]
+#[creusot::strategy("split_vc; auto")]
+#[requires(@x < 1000)]
+#[ensures(@result == @x + 1)]
+pub fn incr(x: u32) -> u32 {
+    x + 1
+}
+
+#[creusot::prover("cvc4")]
+#[creusot::prover("alt-ergo", timeout = 5)]
+#[logic]
+#[variant(n)]
+#[requires(n >= 0)]
+#[ensures(result >= 0)]
+fn count_down(n: Int) -> Int {
+    pearlite! { if n == 0 { 0 } else { count_down(n - 1) } }
+}
@@ -0,0 +1,30 @@
+extern crate creusot_contracts;
+use creusot_contracts::{logic::Real, *};
+
+#[logic]
+pub fn half(x: Real) -> Real {
+    x / Real::from_int(2)
+}
+
+#[logic]
+pub fn norm(x: Real, y: Real) -> Real {
+    (x * x + y * y).sqrt()
+}
+
+#[logic]
+pub fn dist(x: Real, y: Real) -> Real {
+    (x - y).abs()
+}
+
+#[logic]
+#[requires(x >= Real::from_int(0))]
+#[ensures(half(x) <= x)]
+pub fn half_le(x: Real) {}
+
+#[logic]
+#[ensures(-(-x) == x)]
+#[ensures(x.min(y) <= x.max(y))]
+pub fn neg_neg(x: Real, y: Real) {}
+
+#[requires(forall<x: Real> dist(x, x) == Real::from_int(0))]
+pub fn in_contract() {}
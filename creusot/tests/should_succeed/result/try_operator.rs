@@ -0,0 +1,53 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures(a == None ==> result == None)]
+#[ensures(forall<x: u32> a == Some(x) ==> result == Some(x / 2u32))]
+pub fn half(a: Option<u32>) -> Option<u32> {
+    let x = a?;
+    Some(x / 2)
+}
+
+#[ensures(forall<e: u32> r == Err(e) ==> result == Err(e))]
+#[ensures(forall<x: u32> r == Ok(x) ==> result == Ok(x == 0u32))]
+pub fn is_zero(r: Result<u32, u32>) -> Result<bool, u32> {
+    Ok(r? == 0)
+}
+
+pub struct ParseError {
+    pub pos: usize,
+}
+
+pub enum AppError {
+    Parse(usize),
+    Other,
+}
+
+impl From<ParseError> for AppError {
+    #[ensures(result == AppError::Parse(e.pos))]
+    fn from(e: ParseError) -> Self {
+        AppError::Parse(e.pos)
+    }
+}
+
+#[ensures(forall<e: ParseError> r == Err(e) ==> result == Err(AppError::Parse(e.pos)))]
+#[ensures(forall<x: u32> r == Ok(x) ==> result == Ok(x))]
+pub fn lift(r: Result<u32, ParseError>) -> Result<u32, AppError> {
+    let x = r?;
+    Ok(x)
+}
+
+#[ensures(result == AppError::Parse(e.pos))]
+pub fn convert(e: ParseError) -> AppError {
+    e.into()
+}
+
+#[ensures(result == Err(AppError::Other))]
+pub fn fail() -> Result<u32, AppError> {
+    Err(AppError::Other)?;
+    Ok(0)
+}
+
+pub fn convert_generic<T, U: From<T>>(t: T) -> U {
+    t.into()
+}
@@ -0,0 +1,63 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures((^v).deep_model().sorted())]
+#[ensures((@^v).permutation_of(@v))]
+pub fn sort_std(v: &mut [u32]) {
+    v.sort();
+}
+
+#[requires((@v).len() > 0)]
+#[ensures(result == (@v)[(@v).len() - 1])]
+pub fn largest(v: &mut [u32]) -> u32 {
+    v.sort_unstable();
+    proof_assert! { (@v).sorted() };
+    v[v.len() - 1]
+}
+
+#[requires(v.deep_model().sorted())]
+#[ensures(result == v.deep_model().contains(@x))]
+pub fn search(v: &[u32], x: u32) -> bool {
+    match v.binary_search(&x) {
+        Ok(_) => true,
+        Err(_) => false,
+    }
+}
+
+#[ensures(result == v.deep_model().contains(@x))]
+pub fn contains(v: &[u32], x: u32) -> bool {
+    v.contains(&x)
+}
+
+#[ensures(match result { Ok(i) => @i < (@v).len(), Err(i) => @i <= (@v).len() })]
+pub fn search_by(v: &[u32], x: u32) -> Result<usize, usize> {
+    v.binary_search_by(|y| y.cmp(&x))
+}
+
+#[ensures((@^v).permutation_of(@v))]
+pub fn sort_by_key(v: &mut [(u32, u32)]) {
+    v.sort_by_key(|p| p.1)
+}
+
+#[requires((@v).len() == 3)]
+#[ensures((@^v)[0] == (@v)[2])]
+pub fn reverse(v: &mut [u32]) {
+    v.reverse()
+}
+
+#[requires((@v).len() == 3)]
+#[ensures((@^v)[0] == (@v)[1] && (@^v)[2] == (@v)[0])]
+pub fn rotate(v: &mut [u32]) {
+    v.rotate_left(1)
+}
+
+#[requires((@dst).len() == (@src).len())]
+#[ensures(@^dst == @src)]
+pub fn copy(dst: &mut [u32], src: &[u32]) {
+    dst.copy_from_slice(src)
+}
+
+#[ensures(forall<i : Int> 0 <= i && i < (@v).len() ==> (@^v)[i] == 0u32)]
+pub fn zero(v: &mut [u32]) {
+    v.fill(0)
+}
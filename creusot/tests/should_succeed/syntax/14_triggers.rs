@@ -0,0 +1,42 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// Tests that triggers can be attached to quantifiers
+
+#[logic]
+fn f(_: Int) -> Int {
+    pearlite! { 0 }
+}
+
+#[logic]
+fn g(_: Int) -> Int {
+    pearlite! { 0 }
+}
+
+#[predicate]
+pub fn all_positive(s: Seq<Int>) -> bool {
+    pearlite! { forall<i: Int> #[trigger(s[i])] 0 <= i && i < s.len() ==> s[i] > 0 }
+}
+
+#[predicate]
+pub fn some_zero(s: Seq<Int>) -> bool {
+    pearlite! { exists<i: Int> #[trigger(s[i])] 0 <= i && i < s.len() && s[i] == 0 }
+}
+
+#[predicate]
+pub fn multi_trigger() -> bool {
+    pearlite! { forall<i: Int> #[trigger(f(i), g(i))] f(i) == g(i) }
+}
+
+#[predicate]
+pub fn alternative_triggers() -> bool {
+    pearlite! { forall<i: Int> #[trigger(f(i))] #[trigger(g(i))] f(i) == g(i) }
+}
+
+#[requires(forall<i: Int> #[trigger((@v)[i])] 0 <= i && i < (@v).len() ==> (@v)[i] == 0u32)]
+pub fn in_contract(v: Vec<u32>) {}
+
+#[predicate]
+pub fn pattern_binder() -> bool {
+    pearlite! { forall<i: Int, (a, b): (Int, Int)> #[trigger(f(i))] f(i) == f(a) + f(b) }
+}
@@ -0,0 +1,32 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// Tests quantifiers binding several variables, and binding patterns
+
+#[predicate]
+pub fn sorted(s: Seq<Int>) -> bool {
+    pearlite! { forall<i: Int, j: Int> 0 <= i && i <= j && j < s.len() ==> s[i] <= s[j] }
+}
+
+#[predicate]
+pub fn pairs_sorted(s: Seq<(Int, Int)>) -> bool {
+    pearlite! { forall<i: Int, (a, b): (Int, Int)> 0 <= i && i < s.len() && s[i] == (a, b) ==> a <= b }
+}
+
+#[predicate]
+pub fn has_pair(s: Seq<(Int, Int)>) -> bool {
+    pearlite! { exists<(a, b): (Int, Int)> a == b && s.contains((a, b)) }
+}
+
+#[predicate]
+pub fn with_trigger(s: Seq<Int>) -> bool {
+    pearlite! { forall<i: Int, j: Int> #[trigger(s[i], s[j])] 0 <= i && i < j && j < s.len() ==> s[i] != s[j] }
+}
+
+#[logic]
+pub fn sum_pair() -> Int {
+    pearlite! { (|(a, b): (Int, Int)| a + b).get((1, 2)) }
+}
+
+#[requires(forall<i: Int, j: Int> 0 <= i && i < j && j < (@v).len() ==> (@v)[i] <= (@v)[j])]
+pub fn in_contract(v: Vec<u32>) {}
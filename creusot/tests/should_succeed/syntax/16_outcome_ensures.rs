@@ -0,0 +1,50 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub struct Overflow;
+
+#[ensures_ok(r => @r == @a + @b)]
+#[ensures_err(@a + @b > @u32::MAX)]
+pub fn add(a: u32, b: u32) -> Result<u32, Overflow> {
+    match a.checked_add(b) {
+        Some(r) => Ok(r),
+        None => Err(Overflow),
+    }
+}
+
+#[ensures_some(i => @i < (@v).len() && (@v)[@i] == x)]
+#[ensures_none(forall<i: Int> 0 <= i && i < (@v).len() ==> (@v)[i] != x)]
+pub fn find(v: &Vec<u32>, x: u32) -> Option<usize> {
+    let mut i = 0;
+    #[invariant(prev, forall<j: Int> 0 <= j && j < @i ==> (@v)[j] != x)]
+    while i < v.len() {
+        if v[i] == x {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+#[ensures_some((a, b) => x == Some(a) && b == y)]
+pub fn pair(x: Option<u32>, y: u32) -> Option<(u32, u32)> {
+    let a = x?;
+    Some((a, y))
+}
+
+extern_spec! {
+    mod std {
+        mod option {
+            impl<T> Option<T> {
+                #[ensures_none(self == None || exists<t: T> self == Some(t) && optb != None)]
+                #[ensures_some(t => self == Some(t) && optb == None || self == None && optb == Some(t))]
+                fn xor(self, optb: Option<T>) -> Option<T>;
+            }
+        }
+    }
+}
+
+#[ensures_some(t => t == 1u32)]
+pub fn only_one() -> Option<u32> {
+    Some(1).xor(None)
+}
@@ -0,0 +1,33 @@
+extern crate creusot_contracts;
+use creusot_contracts::{logic::Seq, *};
+
+#[logic]
+#[requires(0 <= n)]
+#[ensures(n <= n * n || n == 0)]
+fn square_grows(n: Int) {}
+
+#[ensures((*result).ext_eq(@v))]
+pub fn record(v: &Vec<u32>) -> Ghost<Seq<u32>> {
+    let seen = ghost! { Seq::EMPTY };
+    let mut i = 0;
+    #[invariant(bounded, @i <= (@v).len())]
+    #[invariant(prefix, (*seen).ext_eq((@v).subsequence(0, @i)))]
+    while i < v.len() {
+        let x = v[i];
+        ghost! {
+            let prev = *seen;
+            seen = Ghost::new(prev.push(x));
+        };
+        i += 1;
+    }
+    seen
+}
+
+pub fn lemma_call(n: u32) {
+    let bound = ghost! { pearlite! { @n } };
+    ghost! {
+        square_grows(pearlite! { @n });
+        bound = Ghost::new(pearlite! { @n * @n });
+    };
+    proof_assert!(@n <= *bound || n == 0u32);
+}
@@ -0,0 +1,65 @@
+#![feature(min_specialization)]
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[derive(DeepModel)]
+pub struct Product<A, B> {
+    a: A,
+    b: B,
+}
+
+#[derive(DeepModel)]
+pub enum Sum<A, B> {
+    A(A),
+    B { b: B },
+    C,
+}
+
+#[derive(DeepModel)]
+#[DeepModelTy = "Pair<A::DeepModelTy, B::DeepModelTy>"]
+pub struct Pair<A, B>(A, B);
+
+#[derive(ShallowModel)]
+pub struct Stack<T> {
+    inner: Vec<T>,
+}
+
+#[derive(Resolve)]
+pub struct MutPair<'a, T> {
+    left: &'a mut T,
+    right: &'a mut T,
+}
+
+#[derive(Resolve)]
+pub enum MutEither<'a, T> {
+    Left(&'a mut T),
+    Right(&'a mut T),
+    Neither,
+}
+
+#[ensures(result == (x.deep_model() == y.deep_model()))]
+pub fn product_eq(x: Product<u32, bool>, y: Product<u32, bool>) -> bool {
+    x.a == y.a && x.b == y.b
+}
+
+#[ensures((@result).len() == (@s).len())]
+pub fn stack_inner(s: Stack<u32>) -> Vec<u32> {
+    s.inner
+}
+
+#[ensures(^x == 1u32 && ^y == 2u32)]
+pub fn set_pair(x: &mut u32, y: &mut u32) {
+    let p = MutPair { left: x, right: y };
+    *p.left = 1;
+    *p.right = 2;
+}
+
+#[ensures(^x == 1u32)]
+pub fn set_either(x: &mut u32) {
+    let e = MutEither::Left(x);
+    match e {
+        MutEither::Left(l) => *l = 1,
+        MutEither::Right(r) => *r = 1,
+        MutEither::Neither => (),
+    }
+}
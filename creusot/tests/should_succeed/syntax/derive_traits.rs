@@ -0,0 +1,67 @@
+#![feature(min_specialization)]
+extern crate creusot_contracts;
+use creusot_contracts::{
+    derive,
+    logic::OrdLogic,
+    std::{
+        cmp::PartialEq,
+        hash::{Hash, Hasher},
+    },
+    *,
+};
+
+#[derive(
+    DeepModel,
+    PartialEq,
+    Eq,
+    derive::PartialOrd,
+    derive::Ord,
+    derive::Hash,
+    derive::Debug,
+    derive::Default
+)]
+pub struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[derive(DeepModel, PartialEq, derive::PartialOrd, derive::Debug, derive::Default)]
+pub enum Shape<T> {
+    #[default]
+    Empty,
+    Dot(T),
+    Line { from: T, to: T },
+}
+
+#[ensures(result == (x.deep_model() < y.deep_model()))]
+pub fn point_lt(x: &Point, y: &Point) -> bool {
+    x < y
+}
+
+#[ensures(result == (x.x < y.x || (x.x == y.x && x.y <= y.y)))]
+pub fn point_le(x: &Point, y: &Point) -> bool {
+    x <= y
+}
+
+#[ensures(result == (x.deep_model() <= y.deep_model()))]
+pub fn shape_le<T: PartialOrd + DeepModel>(x: &Shape<T>, y: &Shape<T>) -> bool
+where
+    T::DeepModelTy: OrdLogic,
+{
+    x <= y
+}
+
+#[ensures(result.x == 0u32 && result.y == 0u32)]
+pub fn origin() -> Point {
+    Point::default()
+}
+
+#[ensures(result == Shape::Empty)]
+pub fn empty() -> Shape<u32> {
+    Shape::default()
+}
+
+#[ensures(^h == creusot_contracts::std::hash::hash_log(*h, p.deep_model()))]
+pub fn hash_point<H: Hasher>(p: &Point, h: &mut H) {
+    p.hash(h)
+}
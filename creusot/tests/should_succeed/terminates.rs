@@ -0,0 +1,52 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[terminates]
+#[variant(n)]
+#[ensures(@result == @n)]
+pub fn recursive(n: u32) -> u32 {
+    if n == 0 {
+        0
+    } else {
+        recursive(n - 1) + 1
+    }
+}
+
+#[terminates]
+pub fn loops(v: &Vec<u32>) -> u32 {
+    let mut sum = 0u32;
+    #[variant((@v).len() - produced.len())]
+    for x in v.iter() {
+        sum = sum.wrapping_add(*x);
+    }
+    let mut n = 10u32;
+    #[variant(n)]
+    while n > 0 {
+        n -= 1;
+    }
+    sum + recursive(n)
+}
+
+// Functions which are not required to terminate may loop forever
+pub fn diverges() {
+    loop {}
+}
+
+pub trait Measure {
+    fn measure(&self) -> u32;
+}
+
+impl Measure for u32 {
+    #[terminates]
+    fn measure(&self) -> u32 {
+        *self
+    }
+}
+
+// Trait calls and closure calls are checked against the function they resolve to
+#[terminates]
+pub fn resolved_calls(x: u32) -> u32 {
+    let double = #[terminates]
+    |y: u32| y.wrapping_mul(2);
+    double(x.measure())
+}
@@ -0,0 +1,49 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+use std::cmp::Ordering;
+
+// Implementations of traits specified by an `extern_spec!` must refine that specification
+
+pub struct Pos(pub u32);
+
+impl DeepModel for Pos {
+    type DeepModelTy = Int;
+
+    #[logic]
+    fn deep_model(self) -> Int {
+        pearlite! { @self.0 }
+    }
+}
+
+impl PartialEq for Pos {
+    #[ensures(result == (self.deep_model() == o.deep_model()))]
+    fn eq(&self, o: &Self) -> bool {
+        self.0 == o.0
+    }
+}
+
+impl Eq for Pos {}
+
+impl PartialOrd for Pos {
+    #[ensures(result == Some(self.deep_model().cmp_log(o.deep_model())))]
+    fn partial_cmp(&self, o: &Self) -> Option<Ordering> {
+        Some(self.cmp(o))
+    }
+}
+
+impl Ord for Pos {
+    #[ensures(result == self.deep_model().cmp_log(o.deep_model()))]
+    fn cmp(&self, o: &Self) -> Ordering {
+        self.0.cmp(&o.0)
+    }
+}
+
+// The specification of `PartialEq` only applies to types with a `DeepModel`, so there is nothing
+// to refine here
+pub struct NoModel(pub u32);
+
+impl PartialEq for NoModel {
+    fn eq(&self, o: &Self) -> bool {
+        self.0 == o.0
+    }
+}
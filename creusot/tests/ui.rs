@@ -38,12 +38,50 @@ fn main() {
         std::process::exit(1);
     }
 
+    stale_metadata(&temp_file);
+
     should_fail("tests/should_fail/**/*.rs", |p| run_creusot(p, &temp_file.to_string_lossy()));
     should_succeed("tests/should_succeed/**/*.rs", |p| {
         run_creusot(p, &temp_file.to_string_lossy())
     });
 }
 
+// Metadata produced by another build of Creusot must be rejected rather than decoded
+fn stale_metadata(contracts: &Path) {
+    // The format version directly follows the `CREUSOT-METADATA` magic in the header
+    const FORMAT_VERSION_POSITION: usize = b"CREUSOT-METADATA".len();
+
+    let mut metadata =
+        std::fs::read(contracts).expect("could not read `creusot_contracts` metadata");
+    metadata[FORMAT_VERSION_POSITION] = 0;
+    let stale = contracts.with_file_name("libcreusot_contracts_stale.cmeta");
+    std::fs::write(&stale, metadata).unwrap();
+
+    let mut out = StandardStream::stdout(ColorChoice::Always);
+    write!(&mut out, "Testing stale metadata ... ").unwrap();
+
+    let output =
+        run_creusot(Path::new("tests/should_succeed/while_let.rs"), &stale.to_string_lossy())
+            .unwrap()
+            .output()
+            .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if output.status.success()
+        || !stderr.contains("was produced by another version of Creusot")
+        || !stderr.contains("metadata format 0")
+    {
+        out.set_color(ColorSpec::new().set_fg(Some(Color::Red))).unwrap();
+        writeln!(&mut out, "failure").unwrap();
+        out.reset().unwrap();
+        drop(out);
+        panic!("stale metadata was not rejected:\n{stderr}");
+    }
+
+    out.set_color(ColorSpec::new().set_fg(Some(Color::Green))).unwrap();
+    writeln!(&mut out, "ok").unwrap();
+    out.reset().unwrap();
+}
+
 fn run_creusot(file: &Path, contracts: &str) -> Option<std::process::Command> {
     let header_line = BufReader::new(File::open(&file).unwrap()).lines().nth(0).unwrap().unwrap();
     if header_line.contains("UISKIP") {
@@ -68,6 +106,10 @@ fn run_creusot(file: &Path, contracts: &str) -> Option<std::process::Command> {
         &format!("creusot_contracts={}", normalize_file_path(contracts)),
     ]);
 
+    // Extra arguments for creusot, written as `// CREUSOT_ARG=--flag` on the first line
+    let extra_args = header_line.split("CREUSOT_ARG=").skip(1).map(|arg| arg.trim());
+    cmd.args(extra_args);
+
     cmd.args(&["--", "-Zno-codegen", "--crate-type=lib"]);
     cmd.args(&["--extern", &format!("creusot_contracts={}", creusot_contract_path)]);
 
@@ -18,6 +18,7 @@ mod kw {
     syn::custom_keyword!(exists);
     syn::custom_keyword!(absurd);
     syn::custom_keyword!(pearlite);
+    syn::custom_keyword!(trigger);
 }
 
 ast_enum_of_structs! {
@@ -433,6 +434,7 @@ ast_struct! {
         pub lt_token: Token![<],
         pub args: Punctuated<QuantArg, Token![,]>,
         pub gt_token: Token![>],
+        pub triggers: Vec<Trigger>,
 
         pub term: Box<Term>
     }
@@ -444,6 +446,7 @@ ast_struct! {
         pub lt_token: Token![<],
         pub args: Punctuated<QuantArg, Token![,]>,
         pub gt_token: Token![>],
+        pub triggers: Vec<Trigger>,
 
         pub term: Box<Term>
     }
@@ -451,12 +454,25 @@ ast_struct! {
 
 ast_struct! {
     pub struct QuantArg {
-        pub ident: Ident,
+        pub pat: Pat,
         pub colon_token: Token![:],
         pub ty: Box<Type>,
     }
 }
 
+ast_struct! {
+    /// A trigger for the enclosing quantifier: `#[trigger(a, b)]`.
+    ///
+    /// The quantifier is instantiated for any ground terms matching all the terms of the trigger.
+    pub struct Trigger {
+        pub pound_token: Token![#],
+        pub bracket_token: token::Bracket,
+        pub trigger_token: kw::trigger,
+        pub paren_token: token::Paren,
+        pub terms: Punctuated<Term, Token![,]>,
+    }
+}
+
 ast_struct! {
     pub struct TermAbsurd {
         pub absurd_token: kw::absurd
@@ -598,7 +614,7 @@ pub(crate) fn requires_terminator(expr: &Term) -> bool {
 #[cfg(feature = "parsing")]
 pub(crate) mod parsing {
     use super::*;
-    use syn::parse::{Parse, ParseStream, Result};
+    use syn::parse::{discouraged::Speculative, Parse, ParseStream, Result};
     // use syn::path;
     use std::cmp::Ordering;
 
@@ -1156,8 +1172,9 @@ pub(crate) mod parsing {
             }
 
             if !contains_arguments {
-                let _bang_token: Token![!] = input.parse()?;
-                return Ok(Term::Macro(begin.parse()?));
+                let mac = begin.parse()?;
+                input.advance_to(&begin);
+                return Ok(Term::Macro(mac));
             }
         }
 
@@ -1360,9 +1377,14 @@ pub(crate) mod parsing {
 
             let gt_token: Token![>] = input.parse()?;
 
+            let mut triggers = Vec::new();
+            while input.peek(Token![#]) {
+                triggers.push(input.parse()?);
+            }
+
             let term = input.parse()?;
 
-            Ok(TermForall { forall_token, lt_token, args, gt_token, term })
+            Ok(TermForall { forall_token, lt_token, args, gt_token, triggers, term })
         }
     }
 
@@ -1385,18 +1407,37 @@ pub(crate) mod parsing {
 
             let gt_token: Token![>] = input.parse()?;
 
+            let mut triggers = Vec::new();
+            while input.peek(Token![#]) {
+                triggers.push(input.parse()?);
+            }
+
             let term = input.parse()?;
 
-            Ok(TermExists { exists_token, lt_token, args, gt_token, term })
+            Ok(TermExists { exists_token, lt_token, args, gt_token, triggers, term })
         }
     }
 
     impl Parse for QuantArg {
         fn parse(input: ParseStream) -> Result<Self> {
-            let ident = input.parse()?;
+            let pat = input.parse()?;
             let colon_token = input.parse()?;
             let ty = input.parse()?;
-            Ok(QuantArg { ident, colon_token, ty })
+            Ok(QuantArg { pat, colon_token, ty })
+        }
+    }
+
+    impl Parse for Trigger {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let content;
+            let args;
+            Ok(Trigger {
+                pound_token: input.parse()?,
+                bracket_token: bracketed!(content in input),
+                trigger_token: content.parse()?,
+                paren_token: parenthesized!(args in content),
+                terms: args.parse_terminated(Term::parse)?,
+            })
         }
     }
 
@@ -1896,6 +1937,7 @@ pub(crate) mod printing {
                 input.to_tokens(tokens);
             }
             self.gt_token.to_tokens(tokens);
+            tokens.append_all(&self.triggers);
             self.term.to_tokens(tokens);
         }
     }
@@ -1908,18 +1950,31 @@ pub(crate) mod printing {
                 input.to_tokens(tokens);
             }
             self.gt_token.to_tokens(tokens);
+            tokens.append_all(&self.triggers);
             self.term.to_tokens(tokens);
         }
     }
 
     impl ToTokens for QuantArg {
         fn to_tokens(&self, tokens: &mut TokenStream) {
-            self.ident.to_tokens(tokens);
+            self.pat.to_tokens(tokens);
             self.colon_token.to_tokens(tokens);
             self.ty.to_tokens(tokens);
         }
     }
 
+    impl ToTokens for Trigger {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.pound_token.to_tokens(tokens);
+            self.bracket_token.surround(tokens, |tokens| {
+                self.trigger_token.to_tokens(tokens);
+                self.paren_token.surround(tokens, |tokens| {
+                    self.terms.to_tokens(tokens);
+                })
+            });
+        }
+    }
+
     impl ToTokens for TermAbsurd {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             self.absurd_token.to_tokens(tokens);
@@ -87,8 +87,16 @@ fn test_forall() {
         lt_token: Lt,
         args: [
             QuantArg {
-                ident: Ident(
-                    x,
+                pat: Ident(
+                    PatIdent {
+                        attrs: [],
+                        by_ref: None,
+                        mutability: None,
+                        ident: Ident(
+                            x,
+                        ),
+                        subpat: None,
+                    },
                 ),
                 colon_token: Colon,
                 ty: Path(
@@ -110,6 +118,102 @@ fn test_forall() {
             },
         ],
         gt_token: Gt,
+        triggers: [],
+        term: TermLit {
+            lit: Bool(
+                LitBool {
+                    value: true,
+                },
+            ),
+        },
+    }
+    "###);
+}
+
+#[test]
+fn test_forall_pattern() {
+    snapshot!(quote!(forall<(x, y) : (u32, u32)> true) as Term, @r###"
+    TermForall {
+        forall_token: Keyword [forall],
+        lt_token: Lt,
+        args: [
+            QuantArg {
+                pat: Tuple(
+                    PatTuple {
+                        attrs: [],
+                        paren_token: Paren,
+                        elems: [
+                            Ident(
+                                PatIdent {
+                                    attrs: [],
+                                    by_ref: None,
+                                    mutability: None,
+                                    ident: Ident(
+                                        x,
+                                    ),
+                                    subpat: None,
+                                },
+                            ),
+                            Comma,
+                            Ident(
+                                PatIdent {
+                                    attrs: [],
+                                    by_ref: None,
+                                    mutability: None,
+                                    ident: Ident(
+                                        y,
+                                    ),
+                                    subpat: None,
+                                },
+                            ),
+                        ],
+                    },
+                ),
+                colon_token: Colon,
+                ty: Tuple(
+                    TypeTuple {
+                        paren_token: Paren,
+                        elems: [
+                            Path(
+                                TypePath {
+                                    qself: None,
+                                    path: Path {
+                                        leading_colon: None,
+                                        segments: [
+                                            PathSegment {
+                                                ident: Ident(
+                                                    u32,
+                                                ),
+                                                arguments: None,
+                                            },
+                                        ],
+                                    },
+                                },
+                            ),
+                            Comma,
+                            Path(
+                                TypePath {
+                                    qself: None,
+                                    path: Path {
+                                        leading_colon: None,
+                                        segments: [
+                                            PathSegment {
+                                                ident: Ident(
+                                                    u32,
+                                                ),
+                                                arguments: None,
+                                            },
+                                        ],
+                                    },
+                                },
+                            ),
+                        ],
+                    },
+                ),
+            },
+        ],
+        gt_token: Gt,
+        triggers: [],
         term: TermLit {
             lit: Bool(
                 LitBool {
@@ -129,8 +233,67 @@ fn test_exists() {
         lt_token: Lt,
         args: [
             QuantArg {
-                ident: Ident(
-                    x,
+                pat: Ident(
+                    PatIdent {
+                        attrs: [],
+                        by_ref: None,
+                        mutability: None,
+                        ident: Ident(
+                            x,
+                        ),
+                        subpat: None,
+                    },
+                ),
+                colon_token: Colon,
+                ty: Path(
+                    TypePath {
+                        qself: None,
+                        path: Path {
+                            leading_colon: None,
+                            segments: [
+                                PathSegment {
+                                    ident: Ident(
+                                        u32,
+                                    ),
+                                    arguments: None,
+                                },
+                            ],
+                        },
+                    },
+                ),
+            },
+        ],
+        gt_token: Gt,
+        triggers: [],
+        term: TermLit {
+            lit: Bool(
+                LitBool {
+                    value: true,
+                },
+            ),
+        },
+    }
+    "###);
+}
+
+#[test]
+fn test_forall_trigger() {
+    snapshot!(quote!(forall<x : u32> #[trigger(f(x))] true) as Term, @r###"
+    TermForall {
+        forall_token: Keyword [forall],
+        lt_token: Lt,
+        args: [
+            QuantArg {
+                pat: Ident(
+                    PatIdent {
+                        attrs: [],
+                        by_ref: None,
+                        mutability: None,
+                        ident: Ident(
+                            x,
+                        ),
+                        subpat: None,
+                    },
                 ),
                 colon_token: Colon,
                 ty: Path(
@@ -152,6 +315,55 @@ fn test_exists() {
             },
         ],
         gt_token: Gt,
+        triggers: [
+            Trigger {
+                pound_token: Pound,
+                bracket_token: Bracket,
+                trigger_token: Keyword [trigger],
+                paren_token: Paren,
+                terms: [
+                    TermCall {
+                        func: TermPath {
+                            inner: ExprPath {
+                                attrs: [],
+                                qself: None,
+                                path: Path {
+                                    leading_colon: None,
+                                    segments: [
+                                        PathSegment {
+                                            ident: Ident(
+                                                f,
+                                            ),
+                                            arguments: None,
+                                        },
+                                    ],
+                                },
+                            },
+                        },
+                        paren_token: Paren,
+                        args: [
+                            TermPath {
+                                inner: ExprPath {
+                                    attrs: [],
+                                    qself: None,
+                                    path: Path {
+                                        leading_colon: None,
+                                        segments: [
+                                            PathSegment {
+                                                ident: Ident(
+                                                    x,
+                                                ),
+                                                arguments: None,
+                                            },
+                                        ],
+                                    },
+                                },
+                            },
+                        ],
+                    },
+                ],
+            },
+        ],
         term: TermLit {
             lit: Bool(
                 LitBool {
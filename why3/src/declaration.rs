@@ -296,6 +296,7 @@ pub struct Axiom {
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub struct Goal {
     pub name: Ident,
+    pub attrs: Vec<Attribute>,
     pub goal: Exp,
 }
 
@@ -315,4 +316,5 @@ pub enum LetKind {
     Function,
     Predicate,
     Constant,
+    Lemma,
 }
@@ -117,12 +117,18 @@ pub enum Exp {
     Old(Box<Exp>),
     Absurd,
     Impl(Box<Exp>, Box<Exp>),
-    Forall(Vec<(Ident, Type)>, Box<Exp>),
-    Exists(Vec<(Ident, Type)>, Box<Exp>),
+    Forall(Vec<(Ident, Type)>, Vec<Trigger>, Box<Exp>),
+    Exists(Vec<(Ident, Type)>, Vec<Trigger>, Box<Exp>),
     Sequence(Vec<Exp>),
     FnLit(Box<Exp>),
 }
 
+/// A trigger of a quantifier, made of one or several terms which must all be matched for the
+/// quantifier to be instantiated.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct Trigger(pub Vec<Exp>);
+
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub enum Binder {
@@ -186,8 +192,14 @@ pub fn super_visit_mut<T: ExpMutVisitor>(f: &mut T, exp: &mut Exp) {
             f.visit_mut(l);
             f.visit_mut(r)
         }
-        Exp::Forall(_, e) => f.visit_mut(e),
-        Exp::Exists(_, e) => f.visit_mut(e),
+        Exp::Forall(_, trigs, e) => {
+            trigs.iter_mut().for_each(|t| t.0.iter_mut().for_each(|e| f.visit_mut(e)));
+            f.visit_mut(e)
+        }
+        Exp::Exists(_, trigs, e) => {
+            trigs.iter_mut().for_each(|t| t.0.iter_mut().for_each(|e| f.visit_mut(e)));
+            f.visit_mut(e)
+        }
         Exp::Attr(_, e) => f.visit_mut(e),
         Exp::Ghost(e) => f.visit_mut(e),
         Exp::Record { fields } => fields.iter_mut().for_each(|(_, e)| f.visit_mut(e)),
@@ -250,8 +262,14 @@ pub fn super_visit<T: ExpVisitor>(f: &mut T, exp: &Exp) {
             f.visit(l);
             f.visit(r)
         }
-        Exp::Forall(_, e) => f.visit(e),
-        Exp::Exists(_, e) => f.visit(e),
+        Exp::Forall(_, trigs, e) => {
+            trigs.iter().for_each(|t| t.0.iter().for_each(|e| f.visit(e)));
+            f.visit(e)
+        }
+        Exp::Exists(_, trigs, e) => {
+            trigs.iter().for_each(|t| t.0.iter().for_each(|e| f.visit(e)));
+            f.visit(e)
+        }
         Exp::Attr(_, e) => f.visit(e),
         Exp::Ghost(e) => f.visit(e),
         Exp::Record { fields } => fields.iter().for_each(|(_, e)| f.visit(e)),
@@ -532,8 +550,8 @@ impl Exp {
             Exp::Call(_, _) => App,
             // Exp::Verbatim(_) => Any,
             Exp::Impl(_, _) => Impl,
-            Exp::Forall(_, _) => IfLet,
-            Exp::Exists(_, _) => IfLet,
+            Exp::Forall(_, _, _) => IfLet,
+            Exp::Exists(_, _, _) => IfLet,
             Exp::Ascribe(_, _) => Cast,
             Exp::Absurd => Atom,
             Exp::Pure(_) => Atom,
@@ -567,8 +585,9 @@ impl Exp {
                         self.visit(arg);
                         self.fvs.extend(fvs);
                     }
-                    Exp::Forall(bnds, exp) => {
+                    Exp::Forall(bnds, trigs, exp) => {
                         let fvs = std::mem::take(&mut self.fvs);
+                        trigs.iter().for_each(|t| t.0.iter().for_each(|e| self.visit(e)));
                         self.visit(exp);
 
                         bnds.iter().for_each(|(l, _)| {
@@ -576,8 +595,9 @@ impl Exp {
                         });
                         self.fvs.extend(fvs);
                     }
-                    Exp::Exists(bnds, exp) => {
+                    Exp::Exists(bnds, trigs, exp) => {
                         let fvs = std::mem::take(&mut self.fvs);
+                        trigs.iter().for_each(|t| t.0.iter().for_each(|e| self.visit(e)));
                         self.visit(exp);
 
                         bnds.iter().for_each(|(l, _)| {
@@ -664,20 +684,22 @@ impl Exp {
                             s.visit_mut(br);
                         }
                     }
-                    Exp::Forall(binders, exp) => {
+                    Exp::Forall(binders, trigs, exp) => {
                         let mut subst = self.clone();
                         binders.iter().for_each(|k| {
                             subst.remove(&k.0);
                         });
                         let mut s = &subst;
+                        trigs.iter_mut().for_each(|t| t.0.iter_mut().for_each(|e| s.visit_mut(e)));
                         s.visit_mut(exp);
                     }
-                    Exp::Exists(binders, exp) => {
+                    Exp::Exists(binders, trigs, exp) => {
                         let mut subst = self.clone();
                         binders.iter().for_each(|k| {
                             subst.remove(&k.0);
                         });
                         let mut s = &subst;
+                        trigs.iter_mut().for_each(|t| t.0.iter_mut().for_each(|e| s.visit_mut(e)));
                         s.visit_mut(exp);
                     }
                     _ => super_visit_mut(self, exp),
@@ -3,7 +3,7 @@ use std::{fmt::Display, iter::once};
 use super::*;
 use crate::{
     declaration::*,
-    exp::{AssocDir, BinOp, Binder, Constant, Precedence, UnOp},
+    exp::{AssocDir, BinOp, Binder, Constant, Precedence, Trigger, UnOp},
 };
 use pretty::*;
 
@@ -192,6 +192,9 @@ impl Print for Goal {
         alloc
             .text("goal ")
             .append(self.name.pretty(alloc, env))
+            .append(
+                alloc.concat(self.attrs.iter().map(|a| alloc.space().append(a.pretty(alloc, env)))),
+            )
             .append(" : ")
             .append(self.goal.pretty(alloc, env))
     }
@@ -220,6 +223,7 @@ impl Print for LetDecl {
             Some(LetKind::Function) => doc = doc.append("function "),
             Some(LetKind::Predicate) => doc = doc.append("predicate "),
             Some(LetKind::Constant) => doc = doc.append("constant "),
+            Some(LetKind::Lemma) => doc = doc.append("lemma "),
             None => {}
         }
 
@@ -327,6 +331,23 @@ where
     }
 }
 
+fn pretty_triggers<'b: 'a, 'a, A: DocAllocator<'a>>(
+    alloc: &'a A,
+    env: &mut PrintEnv,
+    trigs: &'a [Trigger],
+) -> DocBuilder<'a, A>
+where
+    A::Doc: Clone,
+{
+    if trigs.is_empty() {
+        alloc.nil()
+    } else {
+        alloc
+            .space()
+            .append(alloc.intersperse(trigs.iter().map(|t| t.pretty(alloc, env)), " | ").brackets())
+    }
+}
+
 impl Print for Logic {
     fn pretty<'b, 'a: 'b, A: DocAllocator<'a>>(
         &'a self,
@@ -463,6 +484,7 @@ impl Print for ValDecl {
             Some(LetKind::Function) => doc = doc.append("function "),
             Some(LetKind::Predicate) => doc = doc.append("predicate "),
             Some(LetKind::Constant) => doc = doc.append("constant "),
+            Some(LetKind::Lemma) => doc = doc.append("lemma "),
             None => {}
         };
 
@@ -726,7 +748,7 @@ impl Print for Exp {
                 .append("else")
                 .append(alloc.line().append(e.pretty(alloc, env)).nest(2).append(alloc.line_()))
                 .group(),
-            Exp::Forall(binders, box exp) => alloc
+            Exp::Forall(binders, trigs, box exp) => alloc
                 .text("forall ")
                 .append(alloc.intersperse(
                     binders.iter().map(|(b, t)| {
@@ -734,9 +756,10 @@ impl Print for Exp {
                     }),
                     ", ",
                 ))
+                .append(pretty_triggers(alloc, env, trigs))
                 .append(" . ")
                 .append(exp.pretty(alloc, env)),
-            Exp::Exists(binders, box exp) => alloc
+            Exp::Exists(binders, trigs, box exp) => alloc
                 .text("exists ")
                 .append(alloc.intersperse(
                     binders.iter().map(|(b, t)| {
@@ -744,6 +767,7 @@ impl Print for Exp {
                     }),
                     ", ",
                 ))
+                .append(pretty_triggers(alloc, env, trigs))
                 .append(" . ")
                 .append(exp.pretty(alloc, env)),
             Exp::Impl(box hyp, box exp) => {
@@ -774,6 +798,19 @@ impl Print for Exp {
     }
 }
 
+impl Print for Trigger {
+    fn pretty<'b, 'a: 'b, A: DocAllocator<'a>>(
+        &'a self,
+        alloc: &'a A,
+        env: &mut PrintEnv,
+    ) -> DocBuilder<'a, A>
+    where
+        A::Doc: Clone,
+    {
+        alloc.intersperse(self.0.iter().map(|e| e.pretty(alloc, env)), ", ")
+    }
+}
+
 impl Print for Binder {
     fn pretty<'b, 'a: 'b, A: DocAllocator<'a>>(
         &'a self,
@@ -822,6 +859,9 @@ impl Print for Statement {
                     );
                 doc
             }
+            Statement::Variant(e) => alloc
+                .text("variant ")
+                .append(alloc.space().append(e.pretty(alloc, env)).append(alloc.space()).braces()),
             Statement::Assume(assump) => {
                 let doc = alloc.text("assume ").append(
                     alloc.space().append(assump.pretty(alloc, env)).append(alloc.space()).braces(),